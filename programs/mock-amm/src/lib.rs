@@ -0,0 +1,215 @@
+//! A constant-product pool (x * y = k) with a flat fee, only meant for local tests of the
+//! xxusd swap adapter. It has no LP tokens: liquidity is added by plain transfers.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("9zAimQYCxXeKJjNFtvAYcKJGQK7stL1GBJwDSNaNtvqX");
+
+pub const POOL_SEED: &[u8] = b"pool";
+pub const POOL_VAULT_SEED: &[u8] = b"pool_vault";
+pub const FEE_BPS: u64 = 30;
+pub const BPS_POWER: u64 = 10_000;
+
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    pub fn initialize_pool(ctx: Context<InitializePool>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.bump = ctx.bumps.pool;
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.vault_a = ctx.accounts.vault_a.key();
+        pool.vault_b = ctx.accounts.vault_b.key();
+        Ok(())
+    }
+
+    pub fn add_liquidity(ctx: Context<AddLiquidity>, amount_a: u64, amount_b: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        for (from, to, amount) in [
+            (&accounts.provider_a, &accounts.vault_a, amount_a),
+            (&accounts.provider_b, &accounts.vault_b, amount_b),
+        ] {
+            let cpi_accounts = Transfer {
+                from: from.to_account_info(),
+                to: to.to_account_info(),
+                authority: accounts.provider.to_account_info(),
+            };
+            token::transfer(CpiContext::new(accounts.token_program.to_account_info(), cpi_accounts), amount)?;
+        }
+        Ok(())
+    }
+
+    /// Swaps `amount_in` of the source mint for the other side of the pool.
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, min_amount_out: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let amount_out = get_amount_out(amount_in, accounts.vault_in.amount, accounts.vault_out.amount)?;
+        require!(amount_out >= min_amount_out, MockAmmError::SlippageExceeded);
+
+        let cpi_accounts = Transfer {
+            from: accounts.user_source.to_account_info(),
+            to: accounts.vault_in.to_account_info(),
+            authority: accounts.user_authority.to_account_info(),
+        };
+        token::transfer(CpiContext::new(accounts.token_program.to_account_info(), cpi_accounts), amount_in)?;
+
+        let pool = &accounts.pool;
+        let seeds = &[POOL_SEED, pool.mint_a.as_ref(), pool.mint_b.as_ref(), &[pool.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: accounts.vault_out.to_account_info(),
+            to: accounts.user_destination.to_account_info(),
+            authority: pool.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, signer),
+            amount_out,
+        )?;
+        Ok(())
+    }
+}
+
+/// Output of a constant-product swap after the fee is taken from the input.
+pub fn get_amount_out(amount_in: u64, reserve_in: u64, reserve_out: u64) -> Result<u64> {
+    require!(amount_in > 0, MockAmmError::InvalidAmount);
+    require!(reserve_in > 0 && reserve_out > 0, MockAmmError::EmptyPool);
+    let amount_in_after_fee = u128::from(amount_in) * u128::from(BPS_POWER - FEE_BPS) / u128::from(BPS_POWER);
+    let amount_out = amount_in_after_fee * u128::from(reserve_out) / (u128::from(reserve_in) + amount_in_after_fee);
+    Ok(amount_out as u64)
+}
+
+#[account]
+pub struct Pool {
+    pub bump: u8,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub vault_a: Pubkey,
+    pub vault_b: Pubkey,
+}
+
+impl Pool {
+    pub const LEN: usize = 8 + 1 + 32 * 4;
+}
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub mint_a: Box<Account<'info, Mint>>,
+    pub mint_b: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Pool::LEN,
+        seeds = [POOL_SEED, mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+    )]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [POOL_VAULT_SEED, pool.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+        token::mint = mint_a,
+        token::authority = pool,
+    )]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [POOL_VAULT_SEED, pool.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+        token::mint = mint_b,
+        token::authority = pool,
+    )]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct AddLiquidity<'info> {
+    pub provider: Signer<'info>,
+
+    #[account(has_one = vault_a, has_one = vault_b)]
+    pub pool: Box<Account<'info, Pool>>,
+
+    #[account(mut)]
+    pub vault_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub vault_b: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub provider_a: Box<Account<'info, TokenAccount>>,
+    #[account(mut)]
+    pub provider_b: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub pool: Box<Account<'info, Pool>>,
+
+    pub user_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_source: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_destination: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = vault_in.key() == pool.vault_a || vault_in.key() == pool.vault_b @MockAmmError::InvalidVault,
+        constraint = vault_in.mint == user_source.mint @MockAmmError::InvalidVault,
+    )]
+    pub vault_in: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = vault_out.key() == pool.vault_a || vault_out.key() == pool.vault_b @MockAmmError::InvalidVault,
+        constraint = vault_out.key() != vault_in.key() @MockAmmError::InvalidVault,
+        constraint = vault_out.mint == user_destination.mint @MockAmmError::InvalidVault,
+    )]
+    pub vault_out: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum MockAmmError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Pool has no liquidity")]
+    EmptyPool,
+    #[msg("Output below minimum amount out")]
+    SlippageExceeded,
+    #[msg("Vault does not belong to the pool")]
+    InvalidVault,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_out_keeps_product() {
+        let amount_out = get_amount_out(1_000, 1_000_000, 1_000_000).unwrap();
+        // 997 in after fee: 997 * 1_000_000 / 1_000_997
+        assert_eq!(amount_out, 996);
+        assert!((1_000_000u128 + 1_000) * (1_000_000u128 - u128::from(amount_out)) >= 1_000_000u128 * 1_000_000);
+    }
+
+    #[test]
+    fn test_empty_pool() {
+        assert!(get_amount_out(1_000, 0, 1_000_000).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+//! A single lending reserve in the style of Kamino, only meant for local tests of the xxusd
+//! lending adapter. Depositing liquidity mints collateral tokens, redeeming burns them. There
+//! are no borrowers: interest is simulated by transferring liquidity into the supply vault,
+//! which raises the exchange rate of the collateral tokens.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+
+declare_id!("GNnYXPYpSCcuyJSuAFAExr5ca2z3xuNrxoUydqFyyLzq");
+
+pub const RESERVE_SEED: &[u8] = b"reserve";
+pub const RESERVE_LIQUIDITY_SEED: &[u8] = b"reserve_liquidity";
+pub const RESERVE_COLLATERAL_MINT_SEED: &[u8] = b"reserve_collateral_mint";
+
+#[program]
+pub mod mock_lending {
+    use super::*;
+
+    pub fn initialize_reserve(ctx: Context<InitializeReserve>) -> Result<()> {
+        let reserve = &mut ctx.accounts.reserve;
+        reserve.bump = ctx.bumps.reserve;
+        reserve.liquidity_mint = ctx.accounts.liquidity_mint.key();
+        reserve.liquidity_supply = ctx.accounts.liquidity_supply.key();
+        reserve.collateral_mint = ctx.accounts.collateral_mint.key();
+        Ok(())
+    }
+
+    pub fn deposit_reserve_liquidity(ctx: Context<ReserveLiquidity>, liquidity_amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let collateral_amount = liquidity_to_collateral(
+            liquidity_amount,
+            accounts.liquidity_supply.amount,
+            accounts.collateral_mint.supply,
+        )?;
+        require!(collateral_amount > 0, MockLendingError::InvalidAmount);
+
+        let cpi_accounts = Transfer {
+            from: accounts.user_liquidity.to_account_info(),
+            to: accounts.liquidity_supply.to_account_info(),
+            authority: accounts.user_authority.to_account_info(),
+        };
+        token::transfer(CpiContext::new(accounts.token_program.to_account_info(), cpi_accounts), liquidity_amount)?;
+
+        let reserve = &accounts.reserve;
+        let seeds = &[RESERVE_SEED, reserve.liquidity_mint.as_ref(), &[reserve.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = MintTo {
+            mint: accounts.collateral_mint.to_account_info(),
+            to: accounts.user_collateral.to_account_info(),
+            authority: reserve.to_account_info(),
+        };
+        token::mint_to(
+            CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, signer),
+            collateral_amount,
+        )?;
+        Ok(())
+    }
+
+    pub fn redeem_reserve_collateral(ctx: Context<ReserveLiquidity>, collateral_amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let liquidity_amount = collateral_to_liquidity(
+            collateral_amount,
+            accounts.liquidity_supply.amount,
+            accounts.collateral_mint.supply,
+        )?;
+        require!(liquidity_amount > 0, MockLendingError::InvalidAmount);
+
+        let cpi_accounts = Burn {
+            mint: accounts.collateral_mint.to_account_info(),
+            from: accounts.user_collateral.to_account_info(),
+            authority: accounts.user_authority.to_account_info(),
+        };
+        token::burn(CpiContext::new(accounts.token_program.to_account_info(), cpi_accounts), collateral_amount)?;
+
+        let reserve = &accounts.reserve;
+        let seeds = &[RESERVE_SEED, reserve.liquidity_mint.as_ref(), &[reserve.bump]];
+        let signer = &[&seeds[..]];
+        let cpi_accounts = Transfer {
+            from: accounts.liquidity_supply.to_account_info(),
+            to: accounts.user_liquidity.to_account_info(),
+            authority: reserve.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, signer),
+            liquidity_amount,
+        )?;
+        Ok(())
+    }
+}
+
+/// Collateral minted for `liquidity_amount`, one to one while the reserve is empty.
+pub fn liquidity_to_collateral(liquidity_amount: u64, total_liquidity: u64, collateral_supply: u64) -> Result<u64> {
+    if collateral_supply == 0 || total_liquidity == 0 {
+        return Ok(liquidity_amount);
+    }
+    let collateral = u128::from(liquidity_amount) * u128::from(collateral_supply) / u128::from(total_liquidity);
+    Ok(u64::try_from(collateral).map_err(|_| MockLendingError::MathOverflow)?)
+}
+
+/// Liquidity paid out for burning `collateral_amount`.
+pub fn collateral_to_liquidity(collateral_amount: u64, total_liquidity: u64, collateral_supply: u64) -> Result<u64> {
+    require!(collateral_amount <= collateral_supply, MockLendingError::InvalidAmount);
+    let liquidity = u128::from(collateral_amount) * u128::from(total_liquidity) / u128::from(collateral_supply);
+    Ok(u64::try_from(liquidity).map_err(|_| MockLendingError::MathOverflow)?)
+}
+
+#[account]
+pub struct Reserve {
+    pub bump: u8,
+    pub liquidity_mint: Pubkey,
+    pub liquidity_supply: Pubkey,
+    pub collateral_mint: Pubkey,
+}
+
+impl Reserve {
+    pub const LEN: usize = 8 + 1 + 32 * 3;
+}
+
+#[derive(Accounts)]
+pub struct InitializeReserve<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub liquidity_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Reserve::LEN,
+        seeds = [RESERVE_SEED, liquidity_mint.key().as_ref()],
+        bump,
+    )]
+    pub reserve: Box<Account<'info, Reserve>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_LIQUIDITY_SEED, reserve.key().as_ref()],
+        bump,
+        token::mint = liquidity_mint,
+        token::authority = reserve,
+    )]
+    pub liquidity_supply: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [RESERVE_COLLATERAL_MINT_SEED, reserve.key().as_ref()],
+        bump,
+        mint::decimals = liquidity_mint.decimals,
+        mint::authority = reserve,
+    )]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ReserveLiquidity<'info> {
+    #[account(has_one = liquidity_supply, has_one = collateral_mint)]
+    pub reserve: Box<Account<'info, Reserve>>,
+
+    #[account(mut)]
+    pub liquidity_supply: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub user_liquidity: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub user_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum MockLendingError {
+    #[msg("Invalid amount")]
+    InvalidAmount,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_deposit_is_one_to_one() {
+        assert_eq!(liquidity_to_collateral(1_000, 0, 0).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_interest_raises_exchange_rate() {
+        // 1_000 collateral backed by 1_100 liquidity after interest
+        assert_eq!(liquidity_to_collateral(1_100, 1_100, 1_000).unwrap(), 1_000);
+        assert_eq!(collateral_to_liquidity(500, 1_100, 1_000).unwrap(), 550);
+    }
+}
@@ -0,0 +1,314 @@
+//! A single-market perpetual futures venue, only meant for local tests of the xxusd perp
+//! adapter. Positions are sized against an admin set mark price and a cumulative funding
+//! index. Trading PnL is settled in the market's collateral when a position is reduced:
+//! gains are paid from the margin vault, which the admin keeps funded, and losses are taken
+//! from the owner's collateral account. Funding is only accounted for.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("Eq6noLyURfY9bT6vCUaxv8M6Cq1Hsv2Bi9TSFjL4bzjR");
+
+pub const MARKET_SEED: &[u8] = b"market";
+pub const POSITION_SEED: &[u8] = b"position";
+pub const MARGIN_VAULT_SEED: &[u8] = b"margin_vault";
+/// Scale of `Market::cumulative_funding`, funding paid per unit of base size.
+pub const FUNDING_PRECISION: i128 = 1_000_000_000;
+
+#[program]
+pub mod mock_perp {
+    use super::*;
+
+    pub fn initialize_market(ctx: Context<InitializeMarket>, mark_price: u64) -> Result<()> {
+        require!(mark_price > 0, MockPerpError::InvalidPrice);
+        let market = &mut ctx.accounts.market;
+        market.bump = ctx.bumps.market;
+        market.authority = ctx.accounts.authority.key();
+        market.mark_price = mark_price;
+        market.cumulative_funding = 0;
+        market.funding_rate = 0;
+        market.funding_updated_at = Clock::get()?.unix_timestamp;
+        market.collateral_mint = ctx.accounts.collateral_mint.key();
+        market.margin_vault = ctx.accounts.margin_vault.key();
+        Ok(())
+    }
+
+    pub fn set_mark_price(ctx: Context<UpdateMarket>, mark_price: u64) -> Result<()> {
+        require!(mark_price > 0, MockPerpError::InvalidPrice);
+        ctx.accounts.market.mark_price = mark_price;
+        Ok(())
+    }
+
+    /// Applies one funding period: a positive `funding_rate` makes longs pay shorts.
+    pub fn accrue_funding(ctx: Context<UpdateMarket>, funding_rate: i64) -> Result<()> {
+        let market = &mut ctx.accounts.market;
+        market.cumulative_funding = market
+            .cumulative_funding
+            .checked_add(i128::from(funding_rate))
+            .ok_or(MockPerpError::MathOverflow)?;
+        market.funding_rate = funding_rate;
+        market.funding_updated_at = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Anyone can pay for a position, only `owner` can trade it.
+    pub fn initialize_position(ctx: Context<InitializePosition>) -> Result<()> {
+        let position = &mut ctx.accounts.position;
+        position.bump = ctx.bumps.position;
+        position.market = ctx.accounts.market.key();
+        position.owner = ctx.accounts.owner.key();
+        position.size = 0;
+        position.entry_price = 0;
+        position.funding_paid = 0;
+        position.last_cumulative_funding = ctx.accounts.market.cumulative_funding;
+        Ok(())
+    }
+
+    /// Changes the signed position size by `size_delta` at the mark price, negative is short.
+    /// Reducing the position settles the PnL of the reduced part.
+    pub fn adjust_position(ctx: Context<AdjustPosition>, size_delta: i64) -> Result<()> {
+        require!(size_delta != 0, MockPerpError::InvalidSize);
+        let accounts = &mut *ctx.accounts;
+        let market = &accounts.market;
+        let position = &mut accounts.position;
+        let pnl = get_realized_pnl(position.size, position.entry_price, size_delta, market.mark_price)?;
+
+        position.funding_paid = position
+            .funding_paid
+            .checked_add(get_unsettled_funding(position.size, position.last_cumulative_funding, market.cumulative_funding)?)
+            .ok_or(MockPerpError::MathOverflow)?;
+        position.last_cumulative_funding = market.cumulative_funding;
+
+        let new_size = position.size.checked_add(size_delta).ok_or(MockPerpError::MathOverflow)?;
+        position.entry_price = get_entry_price(position.size, position.entry_price, size_delta, market.mark_price)?;
+        position.size = new_size;
+
+        if pnl > 0 {
+            let seeds = &[MARKET_SEED, &[market.bump]];
+            let signer = &[&seeds[..]];
+            let cpi_accounts = Transfer {
+                from: accounts.margin_vault.to_account_info(),
+                to: accounts.owner_collateral.to_account_info(),
+                authority: market.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(accounts.token_program.to_account_info(), cpi_accounts, signer),
+                pnl.unsigned_abs(),
+            )?;
+        } else if pnl < 0 {
+            let cpi_accounts = Transfer {
+                from: accounts.owner_collateral.to_account_info(),
+                to: accounts.margin_vault.to_account_info(),
+                authority: accounts.owner.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new(accounts.token_program.to_account_info(), cpi_accounts),
+                pnl.unsigned_abs(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// PnL, in collateral base units at `price`, of the part of the position closed by trading
+/// `size_delta` at `price`. Zero when the trade adds to the position.
+pub fn get_realized_pnl(size: i64, entry_price: u64, size_delta: i64, price: u64) -> Result<i64> {
+    if size == 0 || size.signum() == size_delta.signum() || price == 0 {
+        return Ok(0);
+    }
+    let closed_size = i128::from(size.unsigned_abs().min(size_delta.unsigned_abs())) * i128::from(size.signum());
+    let pnl = closed_size
+        .checked_mul(i128::from(price) - i128::from(entry_price))
+        .ok_or(MockPerpError::MathOverflow)?
+        / i128::from(price);
+    Ok(i64::try_from(pnl).map_err(|_| MockPerpError::MathOverflow)?)
+}
+
+/// Funding owed by a position since it last settled, negative when it is owed funding.
+pub fn get_unsettled_funding(size: i64, last_cumulative_funding: i128, cumulative_funding: i128) -> Result<i64> {
+    let funding = i128::from(size)
+        .checked_mul(cumulative_funding - last_cumulative_funding)
+        .ok_or(MockPerpError::MathOverflow)?
+        / FUNDING_PRECISION;
+    Ok(i64::try_from(funding).map_err(|_| MockPerpError::MathOverflow)?)
+}
+
+/// Average entry price after trading `size_delta` at `price`. Reducing keeps the entry
+/// price, flipping sides starts over at `price`.
+pub fn get_entry_price(size: i64, entry_price: u64, size_delta: i64, price: u64) -> Result<u64> {
+    let new_size = size.checked_add(size_delta).ok_or(MockPerpError::MathOverflow)?;
+    if new_size == 0 {
+        return Ok(0);
+    }
+    if size == 0 || size.signum() != new_size.signum() {
+        return Ok(price);
+    }
+    if size.signum() != size_delta.signum() {
+        return Ok(entry_price);
+    }
+
+    let notional = u128::from(size.unsigned_abs()) * u128::from(entry_price)
+        + u128::from(size_delta.unsigned_abs()) * u128::from(price);
+    Ok((notional / u128::from(new_size.unsigned_abs())) as u64)
+}
+
+#[account]
+pub struct Market {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub mark_price: u64,
+    pub cumulative_funding: i128,
+    /// Rate of the last funding period, scaled by `FUNDING_PRECISION`.
+    pub funding_rate: i64,
+    pub funding_updated_at: i64,
+    pub collateral_mint: Pubkey,
+    /// Pays trading gains and receives trading losses.
+    pub margin_vault: Pubkey,
+}
+
+impl Market {
+    pub const LEN: usize = 8 + 1 + 32 + 8 + 16 + 8 + 8 + 32 + 32;
+}
+
+#[account]
+pub struct Position {
+    pub bump: u8,
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub size: i64,
+    pub entry_price: u64,
+    /// Funding settled so far, negative when the position received funding.
+    pub funding_paid: i64,
+    pub last_cumulative_funding: i128,
+}
+
+impl Position {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 16;
+}
+
+#[derive(Accounts)]
+pub struct InitializeMarket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Market::LEN,
+        seeds = [MARKET_SEED],
+        bump,
+    )]
+    pub market: Box<Account<'info, Market>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [MARGIN_VAULT_SEED, market.key().as_ref()],
+        bump,
+        token::mint = collateral_mint,
+        token::authority = market,
+    )]
+    pub margin_vault: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMarket<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(mut, has_one = authority)]
+    pub market: Box<Account<'info, Market>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializePosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub market: Box<Account<'info, Market>>,
+
+    /// CHECK: only recorded as the position's owner
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = Position::LEN,
+        seeds = [POSITION_SEED, market.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub position: Box<Account<'info, Position>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AdjustPosition<'info> {
+    #[account(has_one = margin_vault)]
+    pub market: Box<Account<'info, Market>>,
+
+    #[account(mut, has_one = market, has_one = owner)]
+    pub position: Box<Account<'info, Position>>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub margin_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = owner,
+    )]
+    pub owner_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[error_code]
+pub enum MockPerpError {
+    #[msg("Invalid price")]
+    InvalidPrice,
+    #[msg("Invalid size")]
+    InvalidSize,
+    #[msg("Math overflow")]
+    MathOverflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entry_price() {
+        // Adding to a short averages the entry price
+        assert_eq!(get_entry_price(-100, 150, -100, 170).unwrap(), 160);
+        // Reducing keeps it, flipping resets it
+        assert_eq!(get_entry_price(-100, 150, 50, 170).unwrap(), 150);
+        assert_eq!(get_entry_price(-100, 150, 150, 170).unwrap(), 170);
+        assert_eq!(get_entry_price(-100, 150, 100, 170).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_realized_pnl() {
+        // Buying back half of a short opened at 150, at 100, gains a third of the closed size
+        assert_eq!(get_realized_pnl(-600, 150, 300, 100).unwrap(), 150);
+        assert_eq!(get_realized_pnl(-600, 150, 300, 200).unwrap(), -75);
+        // Flipping only settles the closed position
+        assert_eq!(get_realized_pnl(-600, 150, 900, 100).unwrap(), 300);
+        // Adding to the position settles nothing
+        assert_eq!(get_realized_pnl(-600, 150, -300, 100).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_shorts_receive_positive_funding() {
+        let funding = get_unsettled_funding(-1_000, 0, FUNDING_PRECISION / 100).unwrap();
+        assert_eq!(funding, -10);
+        assert_eq!(get_unsettled_funding(1_000, 0, FUNDING_PRECISION / 100).unwrap(), 10);
+    }
+}
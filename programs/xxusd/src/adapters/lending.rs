@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::utils::calculate_lending_exchange_rate::calculate_exchange_rate;
+
+/// A lending reserve that takes liquidity and hands out collateral tokens for it.
+pub trait LendingAdapter<'info> {
+    /// Liquidity per collateral token, scaled by `EXCHANGE_RATE_PRECISION`.
+    fn exchange_rate(&self) -> Result<u128>;
+    fn deposit(&self, liquidity_amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+    fn redeem(&self, collateral_amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+}
+
+// Layout of the mock lending program's reserve, after its 8 byte discriminator.
+
+#[derive(AnchorDeserialize)]
+struct ReserveData {
+    _bump: u8,
+    liquidity_mint: Pubkey,
+    liquidity_supply: Pubkey,
+    collateral_mint: Pubkey,
+}
+
+/// Talks to a venue exposing the `mock-lending` interface, Kamino's deposit and redeem flow.
+/// The reserve is only trusted when it is owned by `program`, and its supply vault and
+/// collateral mint must be the accounts passed in.
+pub struct CpiLendingAdapter<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub reserve: &'a AccountInfo<'info>,
+    pub liquidity_supply: &'a Account<'info, TokenAccount>,
+    pub collateral_mint: &'a Account<'info, Mint>,
+    /// Liquidity source on deposit and destination on redeem.
+    pub user_liquidity: &'a AccountInfo<'info>,
+    /// Collateral destination on deposit and source on redeem.
+    pub user_collateral: &'a AccountInfo<'info>,
+    /// The PDA owning both user accounts, signs deposits and redeems.
+    pub owner: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CpiLendingAdapter<'a, 'info> {
+    /// Checks the reserve lends `liquidity_mint` through the accounts held by the adapter.
+    pub fn validate(&self, liquidity_mint: &Pubkey) -> Result<()> {
+        require_keys_eq!(*self.reserve.owner, self.program.key(), XxusdError::InvalidLendingAccount);
+        let data = self.reserve.try_borrow_data()?;
+        require!(data.len() > 8, XxusdError::InvalidLendingAccount);
+        let reserve = ReserveData::deserialize(&mut &data[8..])
+            .map_err(|_| XxusdError::InvalidLendingAccount)?;
+
+        require_keys_eq!(reserve.liquidity_mint, *liquidity_mint, XxusdError::InvalidLendingAccount);
+        require_keys_eq!(reserve.liquidity_supply, self.liquidity_supply.key(), XxusdError::InvalidLendingAccount);
+        require_keys_eq!(reserve.collateral_mint, self.collateral_mint.key(), XxusdError::InvalidLendingAccount);
+        Ok(())
+    }
+
+    fn invoke(&self, name: &str, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let mut data = hash(format!("global:{}", name).as_bytes()).to_bytes()[..8].to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        let instruction = Instruction {
+            program_id: self.program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(self.reserve.key(), false),
+                AccountMeta::new(self.liquidity_supply.key(), false),
+                AccountMeta::new(self.collateral_mint.key(), false),
+                AccountMeta::new(self.user_liquidity.key(), false),
+                AccountMeta::new(self.user_collateral.key(), false),
+                AccountMeta::new_readonly(self.owner.key(), true),
+                AccountMeta::new_readonly(self.token_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.reserve.clone(),
+                self.liquidity_supply.to_account_info(),
+                self.collateral_mint.to_account_info(),
+                self.user_liquidity.clone(),
+                self.user_collateral.clone(),
+                self.owner.clone(),
+                self.token_program.clone(),
+                self.program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
+
+impl<'a, 'info> LendingAdapter<'info> for CpiLendingAdapter<'a, 'info> {
+    fn exchange_rate(&self) -> Result<u128> {
+        calculate_exchange_rate(self.liquidity_supply.amount, self.collateral_mint.supply)
+    }
+
+    fn deposit(&self, liquidity_amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke("deposit_reserve_liquidity", liquidity_amount, signer_seeds)
+    }
+
+    fn redeem(&self, collateral_amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        self.invoke("redeem_reserve_collateral", collateral_amount, signer_seeds)
+    }
+}
@@ -0,0 +1,7 @@
+pub mod lending;
+pub mod perp;
+pub mod swap;
+
+pub use lending::*;
+pub use perp::*;
+pub use swap::*;
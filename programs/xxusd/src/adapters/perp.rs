@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::error::XxusdError;
+
+/// A perp position as seen by the protocol, `size` is negative for a short.
+pub struct PerpPosition {
+    pub size: i64,
+    pub entry_price: u64,
+    /// Funding paid including what has not been settled yet, negative when received.
+    pub funding_paid: i64,
+}
+
+/// A perpetual futures venue holding one position for one market.
+pub trait PerpAdapter<'info> {
+    /// Mark price in USD with 6 decimals.
+    fn mark_price(&self) -> Result<u64>;
+    fn position(&self) -> Result<PerpPosition>;
+    /// Reducing the position settles the PnL of the reduced part in the owner's collateral.
+    fn adjust_position(&self, size_delta: i64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+}
+
+// Layouts of the mock perp program's accounts, after their 8 byte discriminator.
+
+#[derive(AnchorDeserialize)]
+pub(crate) struct MarketData {
+    _bump: u8,
+    _authority: Pubkey,
+    pub mark_price: u64,
+    pub cumulative_funding: i128,
+    pub funding_rate: i64,
+    pub funding_updated_at: i64,
+    _collateral_mint: Pubkey,
+    _margin_vault: Pubkey,
+}
+
+#[derive(AnchorDeserialize)]
+struct PositionData {
+    _bump: u8,
+    market: Pubkey,
+    owner: Pubkey,
+    size: i64,
+    entry_price: u64,
+    funding_paid: i64,
+    last_cumulative_funding: i128,
+}
+
+/// Scale of the venue's funding index and funding rates.
+pub const FUNDING_PRECISION: i128 = 1_000_000_000;
+
+/// Reads `account` as `T` if it is owned by `program`.
+pub(crate) fn load_perp_account<T: AnchorDeserialize>(program: &AccountInfo, account: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*account.owner, program.key(), XxusdError::InvalidPerpAccount);
+    let data = account.try_borrow_data()?;
+    require!(data.len() > 8, XxusdError::InvalidPerpAccount);
+    T::deserialize(&mut &data[8..]).map_err(|_| XxusdError::InvalidPerpAccount.into())
+}
+
+/// Talks to a venue exposing the `mock-perp` interface. Account data is only trusted when
+/// it is owned by `program`, and the position must belong to `owner` on `market`.
+pub struct CpiPerpAdapter<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub market: &'a AccountInfo<'info>,
+    pub position: &'a AccountInfo<'info>,
+    /// The PDA owning the position, signs adjustments.
+    pub owner: &'a AccountInfo<'info>,
+    /// Only needed to adjust the position.
+    pub margin: Option<PerpMarginAccounts<'a, 'info>>,
+}
+
+/// Token accounts the venue settles realized PnL through.
+pub struct PerpMarginAccounts<'a, 'info> {
+    pub margin_vault: &'a AccountInfo<'info>,
+    /// Collateral account of `owner`, pays losses and receives gains.
+    pub owner_collateral: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> CpiPerpAdapter<'a, 'info> {
+    fn load_market(&self) -> Result<MarketData> {
+        load_perp_account(self.program, self.market)
+    }
+
+    fn load_position(&self) -> Result<PositionData> {
+        let position: PositionData = load_perp_account(self.program, self.position)?;
+        require_keys_eq!(position.market, self.market.key(), XxusdError::InvalidPerpAccount);
+        require_keys_eq!(position.owner, self.owner.key(), XxusdError::InvalidPerpAccount);
+        Ok(position)
+    }
+}
+
+impl<'a, 'info> PerpAdapter<'info> for CpiPerpAdapter<'a, 'info> {
+    fn mark_price(&self) -> Result<u64> {
+        Ok(self.load_market()?.mark_price)
+    }
+
+    fn position(&self) -> Result<PerpPosition> {
+        let market = self.load_market()?;
+        let position = self.load_position()?;
+
+        let unsettled_funding = i128::from(position.size)
+            .checked_mul(market.cumulative_funding - position.last_cumulative_funding)
+            .ok_or(XxusdError::MathOverflow)?
+            / FUNDING_PRECISION;
+        let funding_paid = i128::from(position.funding_paid) + unsettled_funding;
+
+        Ok(PerpPosition {
+            size: position.size,
+            entry_price: position.entry_price,
+            funding_paid: i64::try_from(funding_paid).map_err(|_| XxusdError::MathOverflow)?,
+        })
+    }
+
+    fn adjust_position(&self, size_delta: i64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let Some(margin) = &self.margin else {
+            return err!(XxusdError::InvalidPerpAccount);
+        };
+        let mut data = hash(b"global:adjust_position").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&size_delta.to_le_bytes());
+        let instruction = Instruction {
+            program_id: self.program.key(),
+            accounts: vec![
+                AccountMeta::new_readonly(self.market.key(), false),
+                AccountMeta::new(self.position.key(), false),
+                AccountMeta::new_readonly(self.owner.key(), true),
+                AccountMeta::new(margin.margin_vault.key(), false),
+                AccountMeta::new(margin.owner_collateral.key(), false),
+                AccountMeta::new_readonly(margin.token_program.key(), false),
+            ],
+            data,
+        };
+
+        invoke_signed(
+            &instruction,
+            &[
+                self.market.clone(),
+                self.position.clone(),
+                self.owner.clone(),
+                margin.margin_vault.clone(),
+                margin.owner_collateral.clone(),
+                margin.token_program.clone(),
+                self.program.clone(),
+            ],
+            signer_seeds,
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::TokenAccount;
+use anchor_lang::Owners;
+use anchor_spl::token_interface;
+
+use crate::error::XxusdError;
+
+/// Something that can swap tokens out of `source` into `destination`.
+pub trait SwapAdapter<'info> {
+    fn invoke_swap(&self, amount_in: u64, min_amount_out: u64, signer_seeds: &[&[&[u8]]]) -> Result<()>;
+}
+
+/// Swaps through a whitelisted AMM program with an instruction built by the client.
+/// Nothing about the AMM is trusted: the outcome is checked on our token accounts.
+pub struct CpiSwapAdapter<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    /// Owner of the source account, marked as signer in the AMM instruction.
+    pub authority: &'a AccountInfo<'info>,
+    pub accounts: &'a [AccountInfo<'info>],
+    pub data: Vec<u8>,
+    /// Source and destination, the only token accounts of `authority` the AMM may write to.
+    /// Their balances are checked after the swap, the authority's other accounts would not be.
+    pub swap_accounts: [Pubkey; 2],
+}
+
+impl<'a, 'info> CpiSwapAdapter<'a, 'info> {
+    fn is_authority_token_account(&self, account: &AccountInfo) -> Result<bool> {
+        if !token_interface::TokenAccount::owners().contains(account.owner) {
+            return Ok(false);
+        }
+        let data = account.try_borrow_data()?;
+        Ok(token_interface::TokenAccount::try_deserialize(&mut &data[..])
+            .map(|token_account| token_account.owner == self.authority.key())
+            .unwrap_or(false))
+    }
+}
+
+impl<'a, 'info> SwapAdapter<'info> for CpiSwapAdapter<'a, 'info> {
+    fn invoke_swap(&self, _amount_in: u64, _min_amount_out: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        for account in self.accounts {
+            require!(
+                !account.is_writable
+                    || self.swap_accounts.contains(account.key)
+                    || !self.is_authority_token_account(account)?,
+                XxusdError::InvalidSwapAccount
+            );
+        }
+
+        let account_metas = self
+            .accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer || account.key() == self.authority.key(),
+                is_writable: account.is_writable,
+            })
+            .collect();
+        let instruction = Instruction {
+            program_id: self.program.key(),
+            accounts: account_metas,
+            data: self.data.clone(),
+        };
+
+        let mut account_infos = self.accounts.to_vec();
+        account_infos.push(self.program.clone());
+        invoke_signed(&instruction, &account_infos, signer_seeds)?;
+        Ok(())
+    }
+}
+
+pub struct SwapResult {
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+/// Runs a swap and enforces its limits from the balances before and after: at most
+/// `amount_in` may leave `source` and at least `min_amount_out` must reach `destination`.
+pub fn swap_with_min_amount_out<'info, A: SwapAdapter<'info>>(
+    adapter: &A,
+    source: &mut Account<'info, TokenAccount>,
+    destination: &mut Account<'info, TokenAccount>,
+    amount_in: u64,
+    min_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<SwapResult> {
+    let source_balance_before = source.amount;
+    let destination_balance_before = destination.amount;
+    let source_owner = source.owner;
+    let destination_owner = destination.owner;
+
+    adapter.invoke_swap(amount_in, min_amount_out, signer_seeds)?;
+
+    source.reload()?;
+    destination.reload()?;
+    // The AMM had the owner's signature, it must not have handed either account over
+    require!(
+        source.owner == source_owner
+            && destination.owner == destination_owner
+            && source.delegate.is_none()
+            && destination.delegate.is_none(),
+        XxusdError::InvalidSwapAccount
+    );
+    let spent = source_balance_before
+        .checked_sub(source.amount)
+        .ok_or(XxusdError::SwapAmountInExceeded)?;
+    let received = destination
+        .amount
+        .checked_sub(destination_balance_before)
+        .ok_or(XxusdError::SlippageExceeded)?;
+    require!(spent <= amount_in, XxusdError::SwapAmountInExceeded);
+    require!(received >= min_amount_out, XxusdError::SlippageExceeded);
+
+    Ok(SwapResult {
+        amount_in: spent,
+        amount_out: received,
+    })
+}
@@ -82,4 +82,118 @@ pub enum XxusdError {
 
     #[msg("Insufficient Balance")]
     InsufficientBalance,
-}
\ No newline at end of file
+
+    #[msg("Collateral mint is disabled")]
+    CollateralMintDisabled,
+
+    #[msg("Invalid collateral mint decimals")]
+    InvalidCollateralMintDecimals,
+
+    #[msg("Invalid bps value")]
+    InvalidBps,
+
+    #[msg("Term depository is still locked")]
+    TermDepositoryLocked,
+
+    #[msg("Liquid depositories can still serve this redemption")]
+    LiquidDepositoryNotExhausted,
+
+    #[msg("Insufficient term depository amount")]
+    InsufficientTermDepositoryAmount,
+
+    #[msg("Redeemable amount under management cap reached")]
+    RedeemableAmountUnderManagementCapReached,
+
+    #[msg("Invalid cliff period")]
+    InvalidCliffPeriod,
+
+    #[msg("Lock tier is disabled")]
+    LockTierDisabled,
+
+    #[msg("Invalid reward multiplier")]
+    InvalidRewardMultiplier,
+
+    #[msg("Invalid penalty destination")]
+    InvalidPenaltyDestination,
+
+    #[msg("Lock period has ended")]
+    LockPeriodEnded,
+
+    #[msg("Invalid lock tier")]
+    InvalidLockTier,
+
+    #[msg("Invalid lock reward vault")]
+    InvalidLockRewardVault,
+
+    #[msg("No rewards to claim")]
+    NoRewardsToClaim,
+
+    #[msg("Invalid cooldown period")]
+    InvalidCooldownPeriod,
+
+    #[msg("Withdrawals from the staking vault require a cooldown request")]
+    StakingCooldownRequired,
+
+    #[msg("Staking vault has no cooldown, withdraw directly")]
+    StakingCooldownDisabled,
+
+    #[msg("Cooldown period has not ended")]
+    CooldownNotEnded,
+
+    #[msg("Signer does not hold the lock position receipt")]
+    InvalidLockReceipt,
+
+    #[msg("Vesting grant is not revocable")]
+    VestingGrantNotRevocable,
+
+    #[msg("Vesting grant has already been revoked")]
+    VestingGrantRevoked,
+
+    #[msg("Invalid treasury account")]
+    InvalidTreasury,
+
+    #[msg("Max products must cover the existing products and stay within the limit")]
+    InvalidMaxProducts,
+
+    #[msg("Unsupported controller version")]
+    UnsupportedControllerVersion,
+
+    #[msg("Swap program is not whitelisted")]
+    SwapProgramDisabled,
+
+    #[msg("Swap spent more than amount in")]
+    SwapAmountInExceeded,
+
+    #[msg("Swap output below minimum amount out")]
+    SlippageExceeded,
+
+    #[msg("Hedging strategy is disabled")]
+    HedgingStrategyDisabled,
+
+    #[msg("Hedging strategy max allocation exceeded")]
+    MaxAllocationExceeded,
+
+    #[msg("Invalid perp account")]
+    InvalidPerpAccount,
+
+    #[msg("Invalid lending account")]
+    InvalidLendingAccount,
+
+    #[msg("Invalid exchange rate")]
+    InvalidExchangeRate,
+
+    #[msg("Hedging strategy is withdraw-only")]
+    HedgingStrategyWithdrawOnly,
+
+    #[msg("No loss to cover")]
+    NoLossToCover,
+
+    #[msg("Funding rate is stale")]
+    StaleFundingRate,
+
+    #[msg("Invalid max funding rate age")]
+    InvalidMaxFundingRateAge,
+
+    #[msg("Swap writes to a token account of the strategy other than its source and destination")]
+    InvalidSwapAccount,
+}
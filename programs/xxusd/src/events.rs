@@ -108,6 +108,19 @@ pub struct FreezeProgramEvent {
     pub reason: u8,
 }
 
+/// Event called in [instructions::migrate_controller::handler].
+#[event]
+pub struct MigrateControllerEvent {
+    /// The controller version after the migration.
+    #[index]
+    pub version: u8,
+    /// The controller.
+    #[index]
+    pub controller: Pubkey,
+    /// The controller version before the migration, equal to `version` when nothing changed.
+    pub previous_version: u8,
+}
+
 /// Event called in [instructions::lock_xxusd::handler].
 #[event]
 pub struct LockXxusdEvent {
@@ -175,7 +188,8 @@ pub struct SetProductPriceEvent {
     pub price: u64,
 }
 
-/// Event called in [instructions::manage_hedging_strategy::handler].
+/// Event called in [instructions::manage_hedging_strategy::handler] and
+/// [instructions::manage_hedging_strategy::swap_assets].
 #[event]
 pub struct HedgingStrategyEvent {
     /// The controller version.
@@ -184,7 +198,7 @@ pub struct HedgingStrategyEvent {
     /// The controller.
     #[index]
     pub controller: Pubkey,
-    /// The type of hedging strategy operation.
+    /// The type of hedging strategy operation, a `HedgingOperationType`.
     pub operation_type: u8,
     /// The amount involved in the operation.
     pub amount: u64,
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault, Amount};
+use crate::utils::maths::checked_add;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+
+#[derive(Accounts)]
+pub struct AddStakingYield<'info> {
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+        has_one = vault,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = depositor_xxusd.mint == controller.xxusd_mint @XxusdError::InvalidMint,
+        constraint = depositor_xxusd.owner == depositor.key() @XxusdError::InvalidOwner,
+    )]
+    pub depositor_xxusd: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AddStakingYield<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.depositor_xxusd.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.depositor.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Adds xxUSD to the vault without minting shares, raising the sxxUSD share price.
+pub fn handler(ctx: Context<AddStakingYield>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.depositor_xxusd.amount >= amount.value(), XxusdError::InsufficientBalance);
+
+    token::transfer(ctx.accounts.transfer_context(), amount.value())?;
+
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    let new_total_assets = checked_add(staking_vault.get_total_assets(), amount)?;
+    staking_vault.set_total_assets(new_total_assets);
+
+    emit!(AddStakingYieldEvent {
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        total_assets: new_total_assets,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AddStakingYieldEvent {
+    pub depositor: Pubkey,
+    pub amount: Amount,
+    pub total_assets: Amount,
+}
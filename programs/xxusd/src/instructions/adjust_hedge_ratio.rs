@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::oracle::funding_rate::{get_fresh_funding_rate, PerpMarketFundingRateFeed};
+use crate::state::hedging_strategy::{HedgingStrategy, HedgingStrategyType};
+use crate::utils::calculate_hedge_size::calculate_funding_adjusted_hedge_ratio;
+
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+
+/// Permissionless: the ratio is derived from the venue's funding rate and the strategy's
+/// funding policy only.
+#[derive(Accounts)]
+pub struct AdjustHedgeRatio<'info> {
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        constraint = hedging_strategy.strategy_type == HedgingStrategyType::PerpShort @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    /// CHECK: the strategy's perp venue
+    #[account(
+        executable,
+        address = hedging_strategy.venue_program @XxusdError::InvalidPerpAccount,
+    )]
+    pub perp_program: UncheckedAccount<'info>,
+
+    /// CHECK: owner and layout are checked by `PerpMarketFundingRateFeed`
+    #[account(address = hedging_strategy.venue_market @XxusdError::InvalidPerpAccount)]
+    pub perp_market: UncheckedAccount<'info>,
+}
+
+/// Sets the strategy's hedge ratio from the venue's latest funding rate. The next
+/// `rebalance_hedge` resizes the short to the new ratio.
+pub fn handler(ctx: Context<AdjustHedgeRatio>) -> Result<()> {
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+    let feed = PerpMarketFundingRateFeed {
+        program: &ctx.accounts.perp_program,
+        market: &ctx.accounts.perp_market,
+    };
+    let funding_rate = get_fresh_funding_rate(
+        &feed,
+        hedging_strategy.max_funding_rate_age,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let previous_hedge_ratio_bps = hedging_strategy.hedge_ratio_bps;
+    hedging_strategy.hedge_ratio_bps = calculate_funding_adjusted_hedge_ratio(
+        funding_rate.rate,
+        hedging_strategy.funding_rate_threshold_bps,
+        hedging_strategy.funding_sensitivity_bps,
+        hedging_strategy.min_hedge_ratio_bps,
+    )?;
+    hedging_strategy.last_funding_rate = funding_rate.rate;
+
+    emit!(HedgeRatioAdjustmentEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        funding_rate: funding_rate.rate,
+        funding_rate_updated_at: funding_rate.updated_at,
+        previous_hedge_ratio_bps,
+        hedge_ratio_bps: hedging_strategy.hedge_ratio_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HedgeRatioAdjustmentEvent {
+    pub strategy_id: u8,
+    pub funding_rate: i64,
+    pub funding_rate_updated_at: i64,
+    pub previous_hedge_ratio_bps: u16,
+    pub hedge_ratio_bps: u16,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{Controller, KaminoDepository, TermDepository, Amount};
+use crate::utils::calculate_term_redemption::calculate_term_allocation;
+use crate::CONTROLLER_NAMESPACE;
+
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const TERM_DEPOSITORY_SEED: &[u8] = b"term_depository";
+
+#[derive(Accounts)]
+pub struct AllocateTermDeposit<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [TERM_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = term_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub term_depository: Box<Account<'info, TermDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = term_depository,
+    )]
+    pub term_depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> AllocateTermDeposit<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.depository_collateral.to_account_info(),
+            to: self.term_depository_collateral.to_account_info(),
+            authority: self.kamino_depository.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Moves collateral, and the redeemable amount it backs, from the liquid
+/// depository into the term depository of the same collateral mint.
+pub fn handler(ctx: Context<AllocateTermDeposit>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(
+        ctx.accounts.kamino_depository.redeemable_amount_under_management >= amount.to_u128(),
+        XxusdError::InsufficientCollateral
+    );
+
+    let new_term_amount_under_management = calculate_term_allocation(
+        ctx.accounts.term_depository.redeemable_amount_under_management,
+        amount.value(),
+        ctx.accounts.term_depository.redeemable_amount_under_management_cap,
+    )?;
+
+    let collateral_mint = ctx.accounts.collateral_mint.key();
+    let seeds = &[
+        KAMINO_DEPOSITORY_SEED,
+        collateral_mint.as_ref(),
+        &[ctx.accounts.kamino_depository.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount.value())?;
+
+    let kamino_depository = &mut ctx.accounts.kamino_depository;
+    kamino_depository.redeemable_amount_under_management = kamino_depository
+        .redeemable_amount_under_management
+        .checked_sub(amount.to_u128())
+        .ok_or(XxusdError::Overflow)?;
+
+    let term_depository = &mut ctx.accounts.term_depository;
+    term_depository.redeemable_amount_under_management = new_term_amount_under_management;
+
+    emit!(AllocateTermDepositEvent {
+        kamino_depository: kamino_depository.key(),
+        term_depository: term_depository.key(),
+        amount,
+        unlock_time: term_depository.unlock_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AllocateTermDepositEvent {
+    pub kamino_depository: Pubkey,
+    pub term_depository: Pubkey,
+    pub amount: Amount,
+    pub unlock_time: i64,
+}
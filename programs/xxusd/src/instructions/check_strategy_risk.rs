@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{hedging_strategy::HedgingStrategy, KaminoDepository};
+use crate::utils::calculate_strategy_risk::{calculate_drawdown_bps, calculate_leverage_bps, calculate_share_bps};
+
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+
+/// Permissionless: it can only ever restrict a strategy.
+#[derive(Accounts)]
+pub struct CheckStrategyRisk<'info> {
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        constraint = kamino_depository.controller == hedging_strategy.controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Checks the strategy against its risk limits as of its last mark, run `mark_strategy`
+/// first for current values. A breach puts the strategy in withdraw-only mode, which only
+/// the admin can lift through `edit_hedging_strategy`.
+pub fn handler(ctx: Context<CheckStrategyRisk>) -> Result<()> {
+    let hedging_strategy = &ctx.accounts.hedging_strategy;
+    let net_asset_value = hedging_strategy.get_net_asset_value()?;
+    let strategy_amount = u64::try_from(net_asset_value.max(0)).map_err(|_| XxusdError::MathOverflow)?;
+    let total_collateral_amount = ctx.accounts.depository_collateral.amount
+        .checked_add(strategy_amount)
+        .ok_or(XxusdError::MathOverflow)?;
+
+    let collateral_share_bps = calculate_share_bps(strategy_amount, total_collateral_amount)?;
+    let drawdown_bps = calculate_drawdown_bps(
+        hedging_strategy.high_water_mark,
+        hedging_strategy.get_total_pnl()?,
+        hedging_strategy.deposited_amount,
+    )?;
+    let leverage_bps = calculate_leverage_bps(hedging_strategy.position_size, net_asset_value)?;
+
+    let is_breached = |value: u64, limit: u16| limit > 0 && value > u64::from(limit);
+    let collateral_share_breached = is_breached(collateral_share_bps, hedging_strategy.max_collateral_share_bps);
+    let drawdown_breached = is_breached(drawdown_bps, hedging_strategy.max_drawdown_bps);
+    let leverage_breached = is_breached(leverage_bps, hedging_strategy.max_leverage_bps);
+
+    if !(collateral_share_breached || drawdown_breached || leverage_breached) {
+        return Ok(());
+    }
+
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+    hedging_strategy.is_withdraw_only = true;
+
+    emit!(StrategyRiskAlertEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        timestamp: Clock::get()?.unix_timestamp,
+        collateral_share_bps,
+        drawdown_bps,
+        leverage_bps,
+        collateral_share_breached,
+        drawdown_breached,
+        leverage_breached,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct StrategyRiskAlertEvent {
+    pub strategy_id: u8,
+    pub timestamp: i64,
+    pub collateral_share_bps: u64,
+    pub drawdown_bps: u64,
+    pub leverage_bps: u64,
+    pub collateral_share_breached: bool,
+    pub drawdown_breached: bool,
+    pub leverage_breached: bool,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::voting_escrow::VotingEscrow;
+
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
+
+/// Permissionless crank, records the total voting power of every week boundary passed.
+#[derive(Accounts)]
+pub struct CheckpointVotingPower<'info> {
+    #[account(
+        mut,
+        seeds = [VOTING_ESCROW_SEED],
+        bump = voting_escrow.bump,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+}
+
+pub fn handler(ctx: Context<CheckpointVotingPower>) -> Result<()> {
+    let voting_escrow = &mut ctx.accounts.voting_escrow;
+    voting_escrow.checkpoint(Clock::get()?.unix_timestamp)?;
+
+    emit!(VotingPowerCheckpointEvent {
+        timestamp: voting_escrow.last_checkpoint_time,
+        total_voting_power: voting_escrow.get_total_voting_power(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VotingPowerCheckpointEvent {
+    pub timestamp: i64,
+    pub total_voting_power: u64,
+}
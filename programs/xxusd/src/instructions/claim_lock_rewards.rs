@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_position::LockPosition};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+
+#[derive(Accounts)]
+pub struct ClaimLockRewards<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+        has_one = lock_manager,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    /// The position's receipt, held by the signer
+    #[account(
+        constraint = user_receipt.mint == lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.owner == user.key() @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.amount == 1 @XxusdError::InvalidLockReceipt,
+    )]
+    pub user_receipt: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = lock_manager.lock_reward_vault @XxusdError::InvalidLockRewardVault,
+    )]
+    pub lock_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimLockRewards<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.lock_reward_vault.to_account_info(),
+            to: self.user_xxusd.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<ClaimLockRewards>) -> Result<()> {
+    let acc_reward_per_share = ctx.accounts.lock_manager.acc_reward_per_share;
+    let lock_position = &mut ctx.accounts.lock_position;
+    lock_position.settle_rewards(acc_reward_per_share)?;
+
+    let reward_amount = lock_position.pending_rewards;
+    require!(reward_amount > 0, XxusdError::NoRewardsToClaim);
+    lock_position.pending_rewards = 0;
+
+    let seeds = &[
+        LOCK_MANAGER_SEED,
+        &[ctx.accounts.lock_manager.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), reward_amount)?;
+
+    emit!(ClaimLockRewardsEvent {
+        user: ctx.accounts.user.key(),
+        lock_position: ctx.accounts.lock_position.key(),
+        amount: reward_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimLockRewardsEvent {
+    pub user: Pubkey,
+    pub lock_position: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{QueuedRedemption, TermDepository, Amount};
+use crate::utils::calculate_term_redemption::calculate_claimed_term_amounts;
+
+pub const TERM_DEPOSITORY_SEED: &[u8] = b"term_depository";
+pub const QUEUED_REDEMPTION_SEED: &[u8] = b"queued_redemption";
+
+#[derive(Accounts)]
+pub struct ClaimQueuedRedemption<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [TERM_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = term_depository.bump,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub term_depository: Box<Account<'info, TermDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = term_depository,
+    )]
+    pub term_depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = user,
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            QUEUED_REDEMPTION_SEED,
+            term_depository.key().as_ref(),
+            user.key().as_ref(),
+            &queued_redemption.nonce.to_le_bytes(),
+        ],
+        bump = queued_redemption.bump,
+        has_one = user @XxusdError::InvalidOwner,
+        has_one = term_depository @XxusdError::InvalidDepository,
+    )]
+    pub queued_redemption: Box<Account<'info, QueuedRedemption>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimQueuedRedemption<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.term_depository_collateral.to_account_info(),
+            to: self.user_collateral.to_account_info(),
+            authority: self.term_depository.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<ClaimQueuedRedemption>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.term_depository.is_redeemable(current_time),
+        XxusdError::TermDepositoryLocked
+    );
+
+    let redeemable_amount = Amount::new(ctx.accounts.queued_redemption.redeemable_amount);
+    let collateral_amount = Amount::new(ctx.accounts.queued_redemption.collateral_amount);
+
+    let collateral_mint = ctx.accounts.collateral_mint.key();
+    let seeds = &[
+        TERM_DEPOSITORY_SEED,
+        collateral_mint.as_ref(),
+        &[ctx.accounts.term_depository.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(
+        ctx.accounts.transfer_context().with_signer(signer),
+        collateral_amount.value()
+    )?;
+
+    let term_depository = &mut ctx.accounts.term_depository;
+    (term_depository.redeemable_amount_under_management, term_depository.queued_redeemable_amount) =
+        calculate_claimed_term_amounts(
+            term_depository.redeemable_amount_under_management,
+            term_depository.queued_redeemable_amount,
+            redeemable_amount.value(),
+        )?;
+
+    emit!(ClaimQueuedRedemptionEvent {
+        user: ctx.accounts.user.key(),
+        term_depository: term_depository.key(),
+        redeemable_amount,
+        collateral_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimQueuedRedemptionEvent {
+    pub user: Pubkey,
+    pub term_depository: Pubkey,
+    pub redeemable_amount: Amount,
+    pub collateral_amount: Amount,
+}
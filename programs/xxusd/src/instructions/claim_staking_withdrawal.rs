@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault, staking_withdrawal_request::StakingWithdrawalRequest, Amount};
+use crate::utils::maths::checked_sub;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+pub const STAKING_WITHDRAWAL_REQUEST_SEED: &[u8] = b"staking_withdrawal_request";
+
+#[derive(Accounts)]
+pub struct ClaimStakingWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+        has_one = vault,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [STAKING_WITHDRAWAL_REQUEST_SEED, staking_vault.key().as_ref(), user.key().as_ref()],
+        bump = withdrawal_request.bump,
+        has_one = user @XxusdError::InvalidOwner,
+        has_one = staking_vault,
+    )]
+    pub withdrawal_request: Box<Account<'info, StakingWithdrawalRequest>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ClaimStakingWithdrawal<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user_xxusd.to_account_info(),
+            authority: self.staking_vault.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<ClaimStakingWithdrawal>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let withdrawal_request = &ctx.accounts.withdrawal_request;
+    require!(current_time >= withdrawal_request.claimable_time, XxusdError::CooldownNotEnded);
+    let amount = Amount::new(withdrawal_request.amount);
+
+    let seeds = &[
+        STAKING_VAULT_SEED,
+        &[ctx.accounts.staking_vault.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount.value())?;
+
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    let new_pending_withdrawal_amount = checked_sub(staking_vault.get_pending_withdrawal_amount(), amount)?;
+    staking_vault.set_pending_withdrawal_amount(new_pending_withdrawal_amount);
+
+    emit!(ClaimStakingWithdrawalEvent {
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ClaimStakingWithdrawalEvent {
+    pub user: Pubkey,
+    pub amount: Amount,
+}
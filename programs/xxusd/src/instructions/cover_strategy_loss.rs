@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{hedging_strategy::HedgingStrategy, insurance_fund::InsuranceFund, KaminoDepository};
+
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+
+/// Permissionless: the fund only ever pays the depository backing xxUSD.
+#[derive(Accounts)]
+pub struct CoverStrategyLoss<'info> {
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        constraint = kamino_depository.controller == hedging_strategy.controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED, collateral_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+        constraint = insurance_fund.controller == hedging_strategy.controller @XxusdError::InvalidController,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = insurance_fund,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Covers as much of the strategy's realized losses as the insurance fund holds.
+pub fn handler(ctx: Context<CoverStrategyLoss>) -> Result<()> {
+    require!(ctx.accounts.hedging_strategy.uncovered_loss > 0, XxusdError::NoLossToCover);
+
+    let accounts = &mut *ctx.accounts;
+    cover_loss_from_insurance_fund(
+        &mut accounts.hedging_strategy,
+        &mut accounts.insurance_fund,
+        &mut accounts.insurance_fund_vault,
+        &accounts.depository_collateral,
+        &accounts.token_program,
+    )
+}
+
+/// Pays the strategy's uncovered losses, up to the fund's balance, back into the depository.
+pub fn cover_loss_from_insurance_fund<'info>(
+    hedging_strategy: &mut HedgingStrategy,
+    insurance_fund: &mut Account<'info, InsuranceFund>,
+    insurance_fund_vault: &mut Account<'info, TokenAccount>,
+    depository_collateral: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let amount = hedging_strategy.uncovered_loss.min(insurance_fund_vault.amount);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let seeds = &[
+        INSURANCE_FUND_SEED,
+        insurance_fund.collateral_mint.as_ref(),
+        &[insurance_fund.bump],
+    ];
+    let signer = &[&seeds[..]];
+    let cpi_accounts = Transfer {
+        from: insurance_fund_vault.to_account_info(),
+        to: depository_collateral.to_account_info(),
+        authority: insurance_fund.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer),
+        amount,
+    )?;
+
+    insurance_fund.record_draw(amount)?;
+    insurance_fund_vault.reload()?;
+    hedging_strategy.uncovered_loss -= amount;
+
+    emit!(InsuranceFundDrawEvent {
+        collateral_mint: insurance_fund.collateral_mint,
+        strategy_id: hedging_strategy.strategy_id,
+        amount,
+        balance: insurance_fund_vault.amount,
+        uncovered_loss: hedging_strategy.uncovered_loss,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct InsuranceFundDrawEvent {
+    pub collateral_mint: Pubkey,
+    pub strategy_id: u8,
+    pub amount: u64,
+    pub balance: u64,
+    /// Losses of the strategy the fund could not cover yet.
+    pub uncovered_loss: u64,
+}
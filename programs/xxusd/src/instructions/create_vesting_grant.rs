@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, vesting_grant::VestingGrant, Amount};
+use crate::utils::maths::checked_add;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const VESTING_GRANT_SEED: &[u8] = b"vesting_grant";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateVestingGrantParams {
+    /// Distinguishes the recipient's grants; each id can only be used once per recipient.
+    pub grant_id: u64,
+    pub recipient: Pubkey,
+    pub total_amount: Amount,
+    /// May be in the past or the future.
+    pub start_time: i64,
+    pub cliff_period: i64,
+    pub duration: i64,
+    pub revocable: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateVestingGrantParams)]
+pub struct CreateVestingGrant<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VestingGrant::LEN,
+        seeds = [VESTING_GRANT_SEED, params.recipient.as_ref(), &params.grant_id.to_le_bytes()],
+        bump,
+    )]
+    pub vesting_grant: Box<Account<'info, VestingGrant>>,
+
+    /// Funds the grant, the authority must be able to transfer from it
+    #[account(
+        mut,
+        address = lock_manager.treasury @XxusdError::InvalidTreasury,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> CreateVestingGrant<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.treasury.to_account_info(),
+            to: self.lock_vault.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Creates one grant; batches are sent as several of these instructions in a transaction.
+pub fn handler(ctx: Context<CreateVestingGrant>, params: CreateVestingGrantParams) -> Result<()> {
+    require!(params.total_amount.value() > 0, XxusdError::InvalidAmount);
+    require!(params.duration > 0, XxusdError::InvalidLockPeriod);
+    require!(
+        params.cliff_period >= 0 && params.cliff_period <= params.duration,
+        XxusdError::InvalidCliffPeriod
+    );
+
+    token::transfer(ctx.accounts.transfer_context(), params.total_amount.value())?;
+
+    let vesting_grant = &mut ctx.accounts.vesting_grant;
+    vesting_grant.initialize(
+        ctx.bumps.vesting_grant,
+        ctx.accounts.lock_manager.key(),
+        params.recipient,
+        params.grant_id,
+        params.total_amount,
+        params.start_time,
+        params.cliff_period,
+        params.duration,
+        params.revocable,
+    )?;
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    let new_total_locked_amount = checked_add(lock_manager.get_total_locked_amount(), params.total_amount)?;
+    lock_manager.set_total_locked_amount(new_total_locked_amount);
+
+    let controller = &mut ctx.accounts.controller;
+    let new_locked_supply = checked_add(Amount::from_u128(controller.get_locked_xxusd_supply())?, params.total_amount)?;
+    controller.set_locked_xxusd_supply(new_locked_supply.to_u128())?;
+
+    emit!(CreateVestingGrantEvent {
+        vesting_grant: ctx.accounts.vesting_grant.key(),
+        recipient: params.recipient,
+        total_amount: params.total_amount,
+        start_time: params.start_time,
+        cliff_period: params.cliff_period,
+        duration: params.duration,
+        revocable: params.revocable,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CreateVestingGrantEvent {
+    pub vesting_grant: Pubkey,
+    pub recipient: Pubkey,
+    pub total_amount: Amount,
+    pub start_time: i64,
+    pub cliff_period: i64,
+    pub duration: i64,
+    pub revocable: bool,
+}
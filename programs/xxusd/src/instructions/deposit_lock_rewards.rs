@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, Amount};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+
+#[derive(Accounts)]
+pub struct DepositLockRewards<'info> {
+    pub depositor: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        constraint = depositor_xxusd.mint == controller.xxusd_mint @XxusdError::InvalidMint,
+        constraint = depositor_xxusd.owner == depositor.key() @XxusdError::InvalidOwner,
+    )]
+    pub depositor_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        address = lock_manager.lock_reward_vault @XxusdError::InvalidLockRewardVault,
+    )]
+    pub lock_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> DepositLockRewards<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.depositor_xxusd.to_account_info(),
+            to: self.lock_reward_vault.to_account_info(),
+            authority: self.depositor.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Moves protocol yield, already converted to xxUSD, into the lock reward vault and
+/// accrues it to lock positions by weighted amount.
+pub fn handler(ctx: Context<DepositLockRewards>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.depositor_xxusd.amount >= amount.value(), XxusdError::InsufficientBalance);
+
+    token::transfer(ctx.accounts.transfer_context(), amount.value())?;
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    lock_manager.distribute_rewards(amount.value())?;
+
+    emit!(DepositLockRewardsEvent {
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        acc_reward_per_share: lock_manager.acc_reward_per_share,
+        undistributed_rewards: lock_manager.undistributed_rewards,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DepositLockRewardsEvent {
+    pub depositor: Pubkey,
+    pub amount: Amount,
+    pub acc_reward_per_share: u128,
+    /// Rewards still waiting for locked weight or left over from rounding.
+    pub undistributed_rewards: u64,
+}
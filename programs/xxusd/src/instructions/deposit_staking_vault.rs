@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault, Amount};
+use crate::utils::calculate_staking_shares::convert_to_shares;
+use crate::utils::maths::checked_add;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+
+#[derive(Accounts)]
+pub struct DepositStakingVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+        has_one = share_mint @XxusdError::InvalidMint,
+        has_one = vault,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+    )]
+    pub user_sxxusd: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> DepositStakingVault<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.user_xxusd.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn mint_to_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.share_mint.to_account_info(),
+            to: self.user_sxxusd.to_account_info(),
+            authority: self.staking_vault.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<DepositStakingVault>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.user_xxusd.amount >= amount.value(), XxusdError::InsufficientBalance);
+
+    let staking_vault = &ctx.accounts.staking_vault;
+    let shares = convert_to_shares(amount.value(), staking_vault.total_assets, ctx.accounts.share_mint.supply)?;
+    require!(shares > 0, XxusdError::InvalidAmount);
+
+    token::transfer(ctx.accounts.transfer_context(), amount.value())?;
+
+    let seeds = &[
+        STAKING_VAULT_SEED,
+        &[staking_vault.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::mint_to(ctx.accounts.mint_to_context().with_signer(signer), shares)?;
+
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    let new_total_assets = checked_add(staking_vault.get_total_assets(), amount)?;
+    staking_vault.set_total_assets(new_total_assets);
+
+    emit!(DepositStakingVaultEvent {
+        user: ctx.accounts.user.key(),
+        amount,
+        shares,
+        total_assets: new_total_assets,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DepositStakingVaultEvent {
+    pub user: Pubkey,
+    pub amount: Amount,
+    pub shares: u64,
+    pub total_assets: Amount,
+}
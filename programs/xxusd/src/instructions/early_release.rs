@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::{LockManager, PenaltyDestination}, lock_position::LockPosition, voting_escrow::VotingEscrow, Amount};
+use crate::utils::calculate_vested_amount::{calculate_early_release_penalty, calculate_vested_amount};
+use crate::utils::maths::checked_sub;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
+
+#[derive(Accounts)]
+pub struct EarlyRelease<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [VOTING_ESCROW_SEED],
+        bump = voting_escrow.bump,
+        has_one = lock_manager,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+        has_one = lock_manager,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    #[account(
+        mut,
+        address = lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    /// The position's receipt, held by the signer
+    #[account(
+        mut,
+        constraint = user_receipt.mint == lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.owner == user.key() @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.amount == 1 @XxusdError::InvalidLockReceipt,
+    )]
+    pub user_receipt: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Either the lock reward vault or the treasury, depending on the lock manager configuration
+    #[account(
+        mut,
+        constraint = penalty_vault.key() == lock_manager.get_penalty_destination_account() @XxusdError::InvalidPenaltyDestination,
+        constraint = penalty_vault.mint == controller.xxusd_mint @XxusdError::InvalidMint,
+    )]
+    pub penalty_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Pays out the rewards the position earned so far
+    #[account(
+        mut,
+        address = lock_manager.lock_reward_vault @XxusdError::InvalidLockRewardVault,
+    )]
+    pub lock_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EarlyRelease<'info> {
+    fn transfer_context(&self, from: &AccountInfo<'info>, to: &AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: from.clone(),
+            to: to.clone(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn burn_receipt_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.receipt_mint.to_account_info(),
+            from: self.user_receipt.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Exits a lock position before it has fully vested. The vested part is returned as is,
+/// the unvested part is returned minus a penalty that decays until the end of the lock.
+/// Unclaimed lock rewards are paid out along the way and the receipt is burned.
+pub fn handler(ctx: Context<EarlyRelease>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let lock_position = &ctx.accounts.lock_position;
+    let schedule = lock_position.get_vesting_schedule();
+    let vested_amount = calculate_vested_amount(&schedule, current_time)?;
+    let unvested_amount = lock_position.amount.saturating_sub(vested_amount);
+    require!(unvested_amount > 0, XxusdError::LockPeriodNotEnded);
+
+    let remaining_amount = checked_sub(lock_position.get_amount(), lock_position.get_released_amount())?;
+    let penalty_amount = Amount::new(calculate_early_release_penalty(
+        &schedule,
+        unvested_amount,
+        ctx.accounts.lock_manager.early_release_penalty_bps,
+        current_time,
+    )?);
+    let returned_amount = checked_sub(remaining_amount, penalty_amount)?;
+
+    let acc_reward_per_share = ctx.accounts.lock_manager.acc_reward_per_share;
+    ctx.accounts.lock_position.settle_rewards(acc_reward_per_share)?;
+    let reward_amount = ctx.accounts.lock_position.pending_rewards;
+
+    // Pay out the user and the penalty destination
+    let seeds = &[
+        LOCK_MANAGER_SEED,
+        &[ctx.accounts.lock_manager.bump],
+    ];
+    let signer = &[&seeds[..]];
+    if returned_amount.value() > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_context(&ctx.accounts.lock_vault.to_account_info(), &ctx.accounts.user_xxusd.to_account_info())
+                .with_signer(signer),
+            returned_amount.value(),
+        )?;
+    }
+    if penalty_amount.value() > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_context(&ctx.accounts.lock_vault.to_account_info(), &ctx.accounts.penalty_vault.to_account_info())
+                .with_signer(signer),
+            penalty_amount.value(),
+        )?;
+    }
+    if reward_amount > 0 {
+        token::transfer(
+            ctx.accounts
+                .transfer_context(&ctx.accounts.lock_reward_vault.to_account_info(), &ctx.accounts.user_xxusd.to_account_info())
+                .with_signer(signer),
+            reward_amount,
+        )?;
+    }
+
+    token::burn(ctx.accounts.burn_receipt_context(), 1)?;
+
+    // The whole position leaves the lock, the account is closed afterwards
+    let lock_position = &mut ctx.accounts.lock_position;
+    let amount = lock_position.get_amount();
+    lock_position.set_released_amount(amount);
+    lock_position.pending_rewards = 0;
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    let new_total_locked_amount = checked_sub(lock_manager.get_total_locked_amount(), remaining_amount)?;
+    lock_manager.set_total_locked_amount(new_total_locked_amount);
+    lock_manager.update_position_weight(&mut ctx.accounts.lock_position)?;
+    let penalty_destination = lock_manager.penalty_destination;
+    if penalty_destination == PenaltyDestination::RewardPool {
+        // Shared by the lockers that stay
+        lock_manager.distribute_rewards(penalty_amount.value())?;
+    }
+    ctx.accounts.voting_escrow.update_position_voting_power(&mut ctx.accounts.lock_position, current_time)?;
+
+    let controller = &mut ctx.accounts.controller;
+    let new_locked_supply = checked_sub(Amount::from_u128(controller.get_locked_xxusd_supply())?, remaining_amount)?;
+    controller.set_locked_xxusd_supply(new_locked_supply.to_u128())?;
+
+    emit!(EarlyReleaseEvent {
+        user: ctx.accounts.user.key(),
+        lock_position: ctx.accounts.lock_position.key(),
+        returned_amount,
+        penalty_amount,
+        penalty_destination,
+        reward_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EarlyReleaseEvent {
+    pub user: Pubkey,
+    pub lock_position: Pubkey,
+    pub returned_amount: Amount,
+    pub penalty_amount: Amount,
+    pub penalty_destination: PenaltyDestination,
+    pub reward_amount: u64,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{Controller, KaminoDepository};
+use crate::{BPS_POWER, CONTROLLER_NAMESPACE};
+
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditCollateralMintParams {
+    pub oracle: Option<Pubkey>,
+    pub is_enabled: Option<bool>,
+    pub redeemable_amount_under_management_cap: Option<u128>,
+    pub minting_fee_in_bps: Option<u16>,
+    pub redeeming_fee_in_bps: Option<u16>,
+}
+
+#[derive(Accounts)]
+pub struct EditCollateralMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [KAMINO_DEPOSITORY_SEED, kamino_depository.collateral_mint.as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+}
+
+pub fn handler(ctx: Context<EditCollateralMint>, params: EditCollateralMintParams) -> Result<()> {
+    let kamino_depository = &mut ctx.accounts.kamino_depository;
+
+    if let Some(oracle) = params.oracle {
+        kamino_depository.oracle = oracle;
+    }
+
+    if let Some(is_enabled) = params.is_enabled {
+        kamino_depository.is_enabled = is_enabled;
+    }
+
+    if let Some(cap) = params.redeemable_amount_under_management_cap {
+        kamino_depository.redeemable_amount_under_management_cap = cap;
+    }
+
+    if let Some(minting_fee_in_bps) = params.minting_fee_in_bps {
+        require!(u64::from(minting_fee_in_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        kamino_depository.minting_fee_in_bps = minting_fee_in_bps;
+    }
+
+    if let Some(redeeming_fee_in_bps) = params.redeeming_fee_in_bps {
+        require!(u64::from(redeeming_fee_in_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        kamino_depository.redeeming_fee_in_bps = redeeming_fee_in_bps;
+    }
+
+    emit!(EditCollateralMintEvent {
+        depository: kamino_depository.key(),
+        collateral_mint: kamino_depository.collateral_mint,
+        oracle: kamino_depository.oracle,
+        is_enabled: kamino_depository.is_enabled,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EditCollateralMintEvent {
+    pub depository: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub oracle: Pubkey,
+    pub is_enabled: bool,
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::instructions::initialize_hedging_strategy::SetHedgingStrategyEvent;
+use crate::state::{controller::Controller, hedging_strategy::HedgingStrategy, Amount};
+use crate::BPS_POWER;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+
+/// Lowering `max_allocation` below the deposited amount only blocks new deposits.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditHedgingStrategyParams {
+    pub target_allocation_bps: Option<u16>,
+    pub max_allocation: Option<Amount>,
+    pub is_enabled: Option<bool>,
+    pub delta_tolerance_bps: Option<u16>,
+    pub max_collateral_share_bps: Option<u16>,
+    pub max_drawdown_bps: Option<u16>,
+    pub max_leverage_bps: Option<u16>,
+    /// Clears withdraw-only mode once the breach that caused it has been dealt with.
+    pub is_withdraw_only: Option<bool>,
+    pub funding_rate_threshold_bps: Option<u16>,
+    pub funding_sensitivity_bps: Option<u16>,
+    pub min_hedge_ratio_bps: Option<u16>,
+    pub max_funding_rate_age: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct EditHedgingStrategy<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+}
+
+pub fn handler(ctx: Context<EditHedgingStrategy>, params: EditHedgingStrategyParams) -> Result<()> {
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+
+    if let Some(target_allocation_bps) = params.target_allocation_bps {
+        require!(u64::from(target_allocation_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.target_allocation_bps = target_allocation_bps;
+    }
+
+    if let Some(max_allocation) = params.max_allocation {
+        hedging_strategy.max_allocation = max_allocation.value();
+    }
+
+    if let Some(is_enabled) = params.is_enabled {
+        hedging_strategy.is_enabled = is_enabled;
+    }
+
+    if let Some(delta_tolerance_bps) = params.delta_tolerance_bps {
+        require!(u64::from(delta_tolerance_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.delta_tolerance_bps = delta_tolerance_bps;
+    }
+
+    if let Some(max_collateral_share_bps) = params.max_collateral_share_bps {
+        require!(u64::from(max_collateral_share_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.max_collateral_share_bps = max_collateral_share_bps;
+    }
+
+    if let Some(max_drawdown_bps) = params.max_drawdown_bps {
+        require!(u64::from(max_drawdown_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.max_drawdown_bps = max_drawdown_bps;
+    }
+
+    if let Some(max_leverage_bps) = params.max_leverage_bps {
+        hedging_strategy.max_leverage_bps = max_leverage_bps;
+    }
+
+    if let Some(is_withdraw_only) = params.is_withdraw_only {
+        hedging_strategy.is_withdraw_only = is_withdraw_only;
+    }
+
+    if let Some(funding_rate_threshold_bps) = params.funding_rate_threshold_bps {
+        hedging_strategy.funding_rate_threshold_bps = funding_rate_threshold_bps;
+    }
+
+    if let Some(funding_sensitivity_bps) = params.funding_sensitivity_bps {
+        require!(u64::from(funding_sensitivity_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.funding_sensitivity_bps = funding_sensitivity_bps;
+    }
+
+    if let Some(min_hedge_ratio_bps) = params.min_hedge_ratio_bps {
+        require!(u64::from(min_hedge_ratio_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        hedging_strategy.min_hedge_ratio_bps = min_hedge_ratio_bps;
+    }
+
+    if let Some(max_funding_rate_age) = params.max_funding_rate_age {
+        require!(max_funding_rate_age >= 0, XxusdError::InvalidMaxFundingRateAge);
+        hedging_strategy.max_funding_rate_age = max_funding_rate_age;
+    }
+
+    emit!(SetHedgingStrategyEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        strategy_type: hedging_strategy.strategy_type,
+        collateral_mint: hedging_strategy.collateral_mint,
+        target_allocation_bps: hedging_strategy.target_allocation_bps,
+        max_allocation: hedging_strategy.max_allocation,
+        is_enabled: hedging_strategy.is_enabled,
+        delta_tolerance_bps: hedging_strategy.delta_tolerance_bps,
+        max_collateral_share_bps: hedging_strategy.max_collateral_share_bps,
+        max_drawdown_bps: hedging_strategy.max_drawdown_bps,
+        max_leverage_bps: hedging_strategy.max_leverage_bps,
+        is_withdraw_only: hedging_strategy.is_withdraw_only,
+        funding_rate_threshold_bps: hedging_strategy.funding_rate_threshold_bps,
+        funding_sensitivity_bps: hedging_strategy.funding_sensitivity_bps,
+        min_hedge_ratio_bps: hedging_strategy.min_hedge_ratio_bps,
+        max_funding_rate_age: hedging_strategy.max_funding_rate_age,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::instructions::initialize_insurance_fund::SetInsuranceFundEvent;
+use crate::state::{controller::Controller, insurance_fund::InsuranceFund};
+use crate::BPS_POWER;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+
+#[derive(Accounts)]
+pub struct EditInsuranceFund<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED, insurance_fund.collateral_mint.as_ref()],
+        bump = insurance_fund.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+}
+
+pub fn handler(ctx: Context<EditInsuranceFund>, yield_share_bps: u16) -> Result<()> {
+    require!(u64::from(yield_share_bps) <= BPS_POWER, XxusdError::InvalidBps);
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.yield_share_bps = yield_share_bps;
+
+    emit!(SetInsuranceFundEvent {
+        collateral_mint: insurance_fund.collateral_mint,
+        yield_share_bps,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::{LockManager, PenaltyDestination}};
+use crate::BPS_POWER;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditLockManagerParams {
+    pub early_release_penalty_bps: Option<u16>,
+    pub penalty_destination: Option<PenaltyDestination>,
+    pub treasury: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct EditLockManager<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+}
+
+pub fn handler(ctx: Context<EditLockManager>, params: EditLockManagerParams) -> Result<()> {
+    let lock_manager = &mut ctx.accounts.lock_manager;
+
+    if let Some(early_release_penalty_bps) = params.early_release_penalty_bps {
+        require!(u64::from(early_release_penalty_bps) <= BPS_POWER, XxusdError::InvalidBps);
+        lock_manager.early_release_penalty_bps = early_release_penalty_bps;
+    }
+
+    if let Some(treasury) = params.treasury {
+        lock_manager.treasury = treasury;
+    }
+
+    if let Some(penalty_destination) = params.penalty_destination {
+        lock_manager.penalty_destination = penalty_destination;
+    }
+
+    // Penalties can't be sent to the treasury until one is configured
+    require!(
+        lock_manager.get_penalty_destination_account() != Pubkey::default(),
+        XxusdError::InvalidPenaltyDestination
+    );
+
+    emit!(EditLockManagerEvent {
+        early_release_penalty_bps: lock_manager.early_release_penalty_bps,
+        penalty_destination: lock_manager.penalty_destination,
+        treasury: lock_manager.treasury,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EditLockManagerEvent {
+    pub early_release_penalty_bps: u16,
+    pub penalty_destination: PenaltyDestination,
+    pub treasury: Pubkey,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::instructions::initialize_lock_tier::SetLockTierEvent;
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_tier::LockTier};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_TIER_SEED: &[u8] = b"lock_tier";
+
+/// Existing positions keep the period and multiplier they were created with.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditLockTierParams {
+    pub lock_period: Option<i64>,
+    pub reward_multiplier_bps: Option<u16>,
+    pub is_enabled: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct EditLockTier<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_TIER_SEED, &[lock_tier.tier_id]],
+        bump = lock_tier.bump,
+        has_one = lock_manager,
+    )]
+    pub lock_tier: Box<Account<'info, LockTier>>,
+}
+
+pub fn handler(ctx: Context<EditLockTier>, params: EditLockTierParams) -> Result<()> {
+    let lock_tier = &mut ctx.accounts.lock_tier;
+
+    if let Some(lock_period) = params.lock_period {
+        require!(lock_period > 0, XxusdError::InvalidLockPeriod);
+        lock_tier.lock_period = lock_period;
+    }
+
+    if let Some(reward_multiplier_bps) = params.reward_multiplier_bps {
+        require!(reward_multiplier_bps > 0, XxusdError::InvalidRewardMultiplier);
+        lock_tier.reward_multiplier_bps = reward_multiplier_bps;
+    }
+
+    if let Some(is_enabled) = params.is_enabled {
+        lock_tier.is_enabled = is_enabled;
+    }
+
+    emit!(SetLockTierEvent {
+        tier_id: lock_tier.tier_id,
+        lock_period: lock_tier.lock_period,
+        reward_multiplier_bps: lock_tier.reward_multiplier_bps,
+        is_enabled: lock_tier.is_enabled,
+    });
+
+    Ok(())
+}
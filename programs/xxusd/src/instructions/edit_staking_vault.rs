@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditStakingVaultParams {
+    pub cooldown_period: Option<i64>,
+}
+
+#[derive(Accounts)]
+pub struct EditStakingVault<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+}
+
+/// Changing the cooldown does not affect withdrawal requests already made.
+pub fn handler(ctx: Context<EditStakingVault>, params: EditStakingVaultParams) -> Result<()> {
+    let staking_vault = &mut ctx.accounts.staking_vault;
+
+    if let Some(cooldown_period) = params.cooldown_period {
+        require!(cooldown_period >= 0, XxusdError::InvalidCooldownPeriod);
+        staking_vault.cooldown_period = cooldown_period;
+    }
+
+    emit!(EditStakingVaultEvent {
+        staking_vault: staking_vault.key(),
+        cooldown_period: staking_vault.cooldown_period,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EditStakingVaultEvent {
+    pub staking_vault: Pubkey,
+    pub cooldown_period: i64,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::register_swap_program::SetSwapProgramEvent;
+use crate::state::{controller::Controller, swap_program::SwapProgram};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const SWAP_PROGRAM_SEED: &[u8] = b"swap_program";
+
+#[derive(Accounts)]
+pub struct EditSwapProgram<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [SWAP_PROGRAM_SEED, swap_program.program_id.as_ref()],
+        bump = swap_program.bump,
+        has_one = controller,
+    )]
+    pub swap_program: Box<Account<'info, SwapProgram>>,
+}
+
+pub fn handler(ctx: Context<EditSwapProgram>, is_enabled: bool) -> Result<()> {
+    let swap_program = &mut ctx.accounts.swap_program;
+    swap_program.is_enabled = is_enabled;
+
+    emit!(SetSwapProgramEvent {
+        program_id: swap_program.program_id,
+        is_enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{Controller, TermDepository};
+use crate::CONTROLLER_NAMESPACE;
+
+pub const TERM_DEPOSITORY_SEED: &[u8] = b"term_depository";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EditTermDepositoryParams {
+    pub redeemable_amount_under_management_cap: Option<u128>,
+    pub unlock_time: Option<i64>,
+    pub is_liquid: Option<bool>,
+}
+
+#[derive(Accounts)]
+pub struct EditTermDepository<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [TERM_DEPOSITORY_SEED, term_depository.collateral_mint.as_ref()],
+        bump = term_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub term_depository: Box<Account<'info, TermDepository>>,
+}
+
+pub fn handler(ctx: Context<EditTermDepository>, params: EditTermDepositoryParams) -> Result<()> {
+    let term_depository = &mut ctx.accounts.term_depository;
+
+    if let Some(cap) = params.redeemable_amount_under_management_cap {
+        term_depository.redeemable_amount_under_management_cap = cap;
+    }
+
+    if let Some(unlock_time) = params.unlock_time {
+        term_depository.unlock_time = unlock_time;
+    }
+
+    if let Some(is_liquid) = params.is_liquid {
+        term_depository.is_liquid = is_liquid;
+    }
+
+    emit!(EditTermDepositoryEvent {
+        depository: term_depository.key(),
+        unlock_time: term_depository.unlock_time,
+        is_liquid: term_depository.is_liquid,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct EditTermDepositoryEvent {
+    pub depository: Pubkey,
+    pub unlock_time: i64,
+    pub is_liquid: bool,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::state::lock_position::LockPosition;
+use crate::utils::calculate_voting_power;
+
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+
+#[derive(Accounts)]
+pub struct GetVotingPower<'info> {
+    #[account(
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+}
+
+/// Current voting power of a lock position, returned as instruction return data.
+pub fn handler(ctx: Context<GetVotingPower>) -> Result<u64> {
+    let lock_position = &ctx.accounts.lock_position;
+    calculate_voting_power(
+        lock_position.voting_amount,
+        lock_position.voting_end_time,
+        Clock::get()?.unix_timestamp,
+    )
+}
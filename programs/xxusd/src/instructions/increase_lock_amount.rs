@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_position::LockPosition, voting_escrow::VotingEscrow, Amount};
+use crate::utils::calculate_vested_amount::calculate_releasable_amount;
+use crate::utils::maths::{checked_add, checked_sub};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
+
+#[derive(Accounts)]
+pub struct IncreaseLockAmount<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [VOTING_ESCROW_SEED],
+        bump = voting_escrow.bump,
+        has_one = lock_manager,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+        has_one = lock_manager,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    /// The position's receipt, held by the signer
+    #[account(
+        constraint = user_receipt.mint == lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.owner == user.key() @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.amount == 1 @XxusdError::InvalidLockReceipt,
+    )]
+    pub user_receipt: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> IncreaseLockAmount<'info> {
+    fn deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.user_xxusd.to_account_info(),
+            to: self.lock_vault.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn release_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.lock_vault.to_account_info(),
+            to: self.user_xxusd.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Tops up a lock position without moving its end date. Whatever already vested is
+/// released first, then the unvested remainder plus `amount` vests from now until the end.
+pub fn handler(ctx: Context<IncreaseLockAmount>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.user_xxusd.amount >= amount.value(), XxusdError::InsufficientBalance);
+
+    let current_time = Clock::get()?.unix_timestamp;
+    let lock_position = &ctx.accounts.lock_position;
+    let end_time = lock_position.get_end_time();
+    require!(current_time < end_time, XxusdError::LockPeriodEnded);
+
+    // Settle the vested part under the current schedule
+    let released_amount = Amount::new(calculate_releasable_amount(
+        &lock_position.get_vesting_schedule(),
+        lock_position.released_amount,
+        current_time,
+    )?);
+    let remaining_amount = checked_sub(
+        checked_sub(lock_position.get_amount(), lock_position.get_released_amount())?,
+        released_amount,
+    )?;
+    let new_amount = checked_add(remaining_amount, amount)?;
+
+    if released_amount.value() > 0 {
+        let seeds = &[
+            LOCK_MANAGER_SEED,
+            &[ctx.accounts.lock_manager.bump],
+        ];
+        let signer = &[&seeds[..]];
+        token::transfer(ctx.accounts.release_context().with_signer(signer), released_amount.value())?;
+    }
+    token::transfer(ctx.accounts.deposit_context(), amount.value())?;
+
+    let acc_reward_per_share = ctx.accounts.lock_manager.acc_reward_per_share;
+    let lock_position = &mut ctx.accounts.lock_position;
+    lock_position.settle_rewards(acc_reward_per_share)?;
+    lock_position.restart_vesting(current_time, new_amount, end_time - current_time)?;
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    let new_total_locked_amount = checked_sub(
+        checked_add(lock_manager.get_total_locked_amount(), amount)?,
+        released_amount,
+    )?;
+    lock_manager.set_total_locked_amount(new_total_locked_amount);
+    lock_manager.update_position_weight(&mut ctx.accounts.lock_position)?;
+    ctx.accounts.voting_escrow.update_position_voting_power(&mut ctx.accounts.lock_position, current_time)?;
+
+    let controller = &mut ctx.accounts.controller;
+    let new_locked_supply = checked_sub(
+        checked_add(Amount::from_u128(controller.get_locked_xxusd_supply())?, amount)?,
+        released_amount,
+    )?;
+    controller.set_locked_xxusd_supply(new_locked_supply.to_u128())?;
+
+    emit!(IncreaseLockAmountEvent {
+        user: ctx.accounts.user.key(),
+        lock_position: ctx.accounts.lock_position.key(),
+        amount,
+        released_amount,
+        new_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct IncreaseLockAmountEvent {
+    pub user: Pubkey,
+    pub lock_position: Pubkey,
+    /// The amount added to the position.
+    pub amount: Amount,
+    /// The vested amount paid out before the top-up.
+    pub released_amount: Amount,
+    /// The amount now vesting until the end of the lock.
+    pub new_amount: Amount,
+}
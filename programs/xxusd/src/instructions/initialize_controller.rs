@@ -21,7 +21,7 @@ pub struct InitializeController<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + Controller::LEN,
+        space = Controller::space(DEFAULT_MAX_PRODUCTS),
         seeds = [CONTROLLER_NAMESPACE],
         bump
     )]
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, hedging_audit_log::HedgingAuditLog, hedging_strategy::HedgingStrategy};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const HEDGING_AUDIT_LOG_SEED: &[u8] = b"hedging_audit_log";
+
+#[derive(Accounts)]
+pub struct InitializeHedgingAuditLog<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = HedgingAuditLog::LEN,
+        seeds = [HEDGING_AUDIT_LOG_SEED, hedging_strategy.key().as_ref()],
+        bump,
+    )]
+    pub hedging_audit_log: Box<Account<'info, HedgingAuditLog>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the strategy's audit log, required by `manage_hedging_strategy` and `swap_assets`.
+pub fn handler(ctx: Context<InitializeHedgingAuditLog>) -> Result<()> {
+    let hedging_audit_log = &mut ctx.accounts.hedging_audit_log;
+    hedging_audit_log.initialize(ctx.bumps.hedging_audit_log, ctx.accounts.hedging_strategy.key())?;
+
+    emit!(InitializeHedgingAuditLogEvent {
+        strategy_id: ctx.accounts.hedging_strategy.strategy_id,
+        hedging_audit_log: hedging_audit_log.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct InitializeHedgingAuditLogEvent {
+    pub strategy_id: u8,
+    pub hedging_audit_log: Pubkey,
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, hedging_strategy::{HedgingStrategy, HedgingStrategyType}, perp_hedge::PerpHedge, Amount, KaminoDepository};
+use crate::BPS_POWER;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const PERP_HEDGE_SEED: &[u8] = b"perp_hedge";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeHedgingStrategyParams {
+    pub strategy_id: u8,
+    pub strategy_type: HedgingStrategyType,
+    pub target_allocation_bps: u16,
+    pub max_allocation: Amount,
+    /// The perp venue and market for `PerpShort`, the lending program and reserve for `Lending`.
+    pub venue_program: Pubkey,
+    pub venue_market: Pubkey,
+    pub delta_tolerance_bps: u16,
+    /// Risk limits, zero for no limit.
+    pub max_collateral_share_bps: u16,
+    pub max_drawdown_bps: u16,
+    pub max_leverage_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeHedgingStrategyParams)]
+pub struct InitializeHedgingStrategy<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    /// Strategies can only be funded from a registered depository of the same collateral
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = HedgingStrategy::LEN,
+        seeds = [HEDGING_STRATEGY_SEED, &[params.strategy_id]],
+        bump,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_vault: Box<Account<'info, TokenAccount>>,
+
+    /// `PerpShort` only: claims the collateral mint's single perp hedge
+    #[account(
+        init,
+        payer = authority,
+        space = PerpHedge::LEN,
+        seeds = [PERP_HEDGE_SEED, collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub perp_hedge: Option<Box<Account<'info, PerpHedge>>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeHedgingStrategy>, params: InitializeHedgingStrategyParams) -> Result<()> {
+    require!(u64::from(params.target_allocation_bps) <= BPS_POWER, XxusdError::InvalidBps);
+    require!(u64::from(params.delta_tolerance_bps) <= BPS_POWER, XxusdError::InvalidBps);
+    require!(u64::from(params.max_collateral_share_bps) <= BPS_POWER, XxusdError::InvalidBps);
+    require!(u64::from(params.max_drawdown_bps) <= BPS_POWER, XxusdError::InvalidBps);
+    require!(
+        (params.strategy_type == HedgingStrategyType::PerpShort) == ctx.accounts.perp_hedge.is_some(),
+        XxusdError::InvalidPerpAccount
+    );
+
+    if let Some(perp_hedge) = &mut ctx.accounts.perp_hedge {
+        perp_hedge.initialize(
+            ctx.bumps.perp_hedge.ok_or(XxusdError::InvalidPerpAccount)?,
+            ctx.accounts.collateral_mint.key(),
+            params.strategy_id,
+        )?;
+    }
+
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+    hedging_strategy.initialize(
+        ctx.bumps.hedging_strategy,
+        ctx.accounts.controller.key(),
+        params.strategy_id,
+        params.strategy_type,
+        ctx.accounts.collateral_mint.key(),
+        params.target_allocation_bps,
+        params.max_allocation.value(),
+        params.venue_program,
+        params.venue_market,
+        params.delta_tolerance_bps,
+    )?;
+    hedging_strategy.set_risk_limits(
+        params.max_collateral_share_bps,
+        params.max_drawdown_bps,
+        params.max_leverage_bps,
+    );
+
+    emit!(SetHedgingStrategyEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        strategy_type: hedging_strategy.strategy_type,
+        collateral_mint: hedging_strategy.collateral_mint,
+        target_allocation_bps: hedging_strategy.target_allocation_bps,
+        max_allocation: hedging_strategy.max_allocation,
+        is_enabled: hedging_strategy.is_enabled,
+        delta_tolerance_bps: hedging_strategy.delta_tolerance_bps,
+        max_collateral_share_bps: hedging_strategy.max_collateral_share_bps,
+        max_drawdown_bps: hedging_strategy.max_drawdown_bps,
+        max_leverage_bps: hedging_strategy.max_leverage_bps,
+        is_withdraw_only: hedging_strategy.is_withdraw_only,
+        funding_rate_threshold_bps: hedging_strategy.funding_rate_threshold_bps,
+        funding_sensitivity_bps: hedging_strategy.funding_sensitivity_bps,
+        min_hedge_ratio_bps: hedging_strategy.min_hedge_ratio_bps,
+        max_funding_rate_age: hedging_strategy.max_funding_rate_age,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetHedgingStrategyEvent {
+    pub strategy_id: u8,
+    pub strategy_type: HedgingStrategyType,
+    pub collateral_mint: Pubkey,
+    pub target_allocation_bps: u16,
+    pub max_allocation: u64,
+    pub is_enabled: bool,
+    pub delta_tolerance_bps: u16,
+    pub max_collateral_share_bps: u16,
+    pub max_drawdown_bps: u16,
+    pub max_leverage_bps: u16,
+    pub is_withdraw_only: bool,
+    pub funding_rate_threshold_bps: u16,
+    pub funding_sensitivity_bps: u16,
+    pub min_hedge_ratio_bps: u16,
+    pub max_funding_rate_age: i64,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, insurance_fund::InsuranceFund};
+use crate::BPS_POWER;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = InsuranceFund::LEN,
+        seeds = [INSURANCE_FUND_SEED, collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = insurance_fund,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeInsuranceFund>, yield_share_bps: u16) -> Result<()> {
+    require!(u64::from(yield_share_bps) <= BPS_POWER, XxusdError::InvalidBps);
+
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.initialize(
+        ctx.bumps.insurance_fund,
+        ctx.accounts.controller.key(),
+        ctx.accounts.collateral_mint.key(),
+        yield_share_bps,
+    )?;
+
+    emit!(SetInsuranceFundEvent {
+        collateral_mint: insurance_fund.collateral_mint,
+        yield_share_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetInsuranceFundEvent {
+    pub collateral_mint: Pubkey,
+    pub yield_share_bps: u16,
+}
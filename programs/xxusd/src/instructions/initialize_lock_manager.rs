@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_REWARD_VAULT_SEED: &[u8] = b"lock_reward_vault";
+pub const DEFAULT_EARLY_RELEASE_PENALTY_BPS: u16 = 5_000;
+
+#[derive(Accounts)]
+pub struct InitializeLockManager<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+        has_one = xxusd_mint @XxusdError::InvalidMint,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub xxusd_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LockManager::LEN,
+        seeds = [LOCK_MANAGER_SEED],
+        bump,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LOCK_REWARD_VAULT_SEED],
+        bump,
+        token::mint = xxusd_mint,
+        token::authority = lock_manager,
+    )]
+    pub lock_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeLockManager>) -> Result<()> {
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    lock_manager.initialize(
+        ctx.bumps.lock_manager,
+        ctx.accounts.controller.key(),
+        ctx.accounts.lock_reward_vault.key(),
+        DEFAULT_EARLY_RELEASE_PENALTY_BPS,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_tier::LockTier};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_TIER_SEED: &[u8] = b"lock_tier";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeLockTierParams {
+    pub tier_id: u8,
+    pub lock_period: i64,
+    pub reward_multiplier_bps: u16,
+}
+
+#[derive(Accounts)]
+#[instruction(params: InitializeLockTierParams)]
+pub struct InitializeLockTier<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LockTier::LEN,
+        seeds = [LOCK_TIER_SEED, &[params.tier_id]],
+        bump,
+    )]
+    pub lock_tier: Box<Account<'info, LockTier>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeLockTier>, params: InitializeLockTierParams) -> Result<()> {
+    require!(params.lock_period > 0, XxusdError::InvalidLockPeriod);
+    require!(params.reward_multiplier_bps > 0, XxusdError::InvalidRewardMultiplier);
+
+    let lock_tier = &mut ctx.accounts.lock_tier;
+    lock_tier.initialize(
+        ctx.bumps.lock_tier,
+        ctx.accounts.lock_manager.key(),
+        params.tier_id,
+        params.lock_period,
+        params.reward_multiplier_bps,
+    )?;
+
+    emit!(SetLockTierEvent {
+        tier_id: lock_tier.tier_id,
+        lock_period: lock_tier.lock_period,
+        reward_multiplier_bps: lock_tier.reward_multiplier_bps,
+        is_enabled: lock_tier.is_enabled,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetLockTierEvent {
+    pub tier_id: u8,
+    pub lock_period: i64,
+    pub reward_multiplier_bps: u16,
+    pub is_enabled: bool,
+}
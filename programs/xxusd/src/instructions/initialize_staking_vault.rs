@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+pub const SXXUSD_MINT_SEED: &[u8] = b"sxxusd_mint";
+
+#[derive(Accounts)]
+pub struct InitializeStakingVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+        has_one = xxusd_mint @XxusdError::InvalidMint,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub xxusd_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = StakingVault::LEN,
+        seeds = [STAKING_VAULT_SEED],
+        bump,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(
+        init,
+        payer = authority,
+        seeds = [SXXUSD_MINT_SEED],
+        bump,
+        mint::decimals = xxusd_mint.decimals,
+        mint::authority = staking_vault,
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = xxusd_mint,
+        associated_token::authority = staking_vault,
+    )]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<InitializeStakingVault>, cooldown_period: i64) -> Result<()> {
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    staking_vault.initialize(
+        ctx.bumps.staking_vault,
+        ctx.accounts.controller.key(),
+        ctx.accounts.share_mint.key(),
+        ctx.accounts.vault.key(),
+        cooldown_period,
+    )?;
+
+    emit!(InitializeStakingVaultEvent {
+        staking_vault: staking_vault.key(),
+        share_mint: staking_vault.share_mint,
+        cooldown_period,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct InitializeStakingVaultEvent {
+    pub staking_vault: Pubkey,
+    pub share_mint: Pubkey,
+    pub cooldown_period: i64,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{controller::Controller, lock_manager::LockManager, voting_escrow::VotingEscrow};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
+
+#[derive(Accounts)]
+pub struct InitializeVotingEscrow<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = VotingEscrow::LEN,
+        seeds = [VOTING_ESCROW_SEED],
+        bump,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitializeVotingEscrow>) -> Result<()> {
+    let voting_escrow = &mut ctx.accounts.voting_escrow;
+    voting_escrow.initialize(
+        ctx.bumps.voting_escrow,
+        ctx.accounts.lock_manager.key(),
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    Ok(())
+}
@@ -1,18 +1,40 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
 
 use crate::error::XxusdError;
-use crate::state::{controller::Controller, lock_manager::LockManager, Amount, Timestamp};
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_position::{LockPosition, ReleaseInterval}, lock_tier::LockTier, voting_escrow::VotingEscrow, Amount, Timestamp};
 use crate::utils::maths::checked_add;
 
 pub const CONTROLLER_SEED: &[u8] = b"controller";
 pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
+pub const LOCK_TIER_SEED: &[u8] = b"lock_tier";
+pub const LOCK_RECEIPT_SEED: &[u8] = b"lock_receipt";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LockXxusdParams {
+    /// Distinguishes the user's positions; each nonce can only be used once per user.
+    pub nonce: u64,
+    pub amount: Amount,
+    /// The lock tier, which sets the lock period and reward multiplier.
+    pub tier_id: u8,
+    /// Nothing can be released before `start + cliff_period`. Zero for no cliff.
+    pub cliff_period: Timestamp,
+    pub release_interval: ReleaseInterval,
+}
 
 #[derive(Accounts)]
+#[instruction(params: LockXxusdParams)]
 pub struct LockXxusd<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// Receives the position's receipt. Pass `user` to lock for yourself.
+    /// CHECK: only used as the owner of the receipt token account
+    pub beneficiary: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [CONTROLLER_SEED],
@@ -27,6 +49,31 @@ pub struct LockXxusd<'info> {
     )]
     pub lock_manager: Box<Account<'info, LockManager>>,
 
+    #[account(
+        mut,
+        seeds = [VOTING_ESCROW_SEED],
+        bump = voting_escrow.bump,
+        has_one = lock_manager,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+
+    #[account(
+        seeds = [LOCK_TIER_SEED, &[params.tier_id]],
+        bump = lock_tier.bump,
+        has_one = lock_manager,
+        constraint = lock_tier.is_enabled @XxusdError::LockTierDisabled,
+    )]
+    pub lock_tier: Box<Account<'info, LockTier>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = LockPosition::LEN,
+        seeds = [LOCK_POSITION_SEED, user.key().as_ref(), &params.nonce.to_le_bytes()],
+        bump,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
     #[account(
         mut,
         constraint = user_xxusd.owner == user.key() @XxusdError::InvalidOwner,
@@ -41,7 +88,29 @@ pub struct LockXxusd<'info> {
     )]
     pub lock_vault: Box<Account<'info, TokenAccount>>,
 
+    /// One receipt token per position, whoever holds it can release the position
+    #[account(
+        init,
+        payer = user,
+        seeds = [LOCK_RECEIPT_SEED, lock_position.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = lock_manager,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = beneficiary,
+    )]
+    pub beneficiary_receipt: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> LockXxusd<'info> {
@@ -53,12 +122,30 @@ impl<'info> LockXxusd<'info> {
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
+
+    fn mint_receipt_context(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.receipt_mint.to_account_info(),
+            to: self.beneficiary_receipt.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
 }
 
-pub fn handler(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp) -> Result<()> {
+pub fn handler(ctx: Context<LockXxusd>, params: LockXxusdParams) -> Result<()> {
+    let LockXxusdParams { nonce, amount, tier_id, cliff_period, release_interval } = params;
+    let current_time = Clock::get()?.unix_timestamp;
+    let lock_period = Timestamp::new(ctx.accounts.lock_tier.lock_period);
+    let reward_multiplier_bps = ctx.accounts.lock_tier.reward_multiplier_bps;
+
     // Validate lock amount and period
     require!(amount.value() > 0, XxusdError::InvalidCollateralAmount);
     require!(lock_period.value() > 0, XxusdError::InvalidLockPeriod);
+    require!(
+        cliff_period.value() >= 0 && cliff_period.value() <= lock_period.value(),
+        XxusdError::InvalidCliffPeriod
+    );
     require!(ctx.accounts.user_xxusd.amount >= amount.value(), XxusdError::InsufficientBalance);
 
     // Transfer xxUSD from user to lock vault
@@ -70,11 +157,33 @@ pub fn handler(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp)
     let current_locked_amount = lock_manager.get_total_locked_amount();
     let new_total_locked_amount = checked_add(current_locked_amount, amount)?;
     lock_manager.set_total_locked_amount(new_total_locked_amount);
-    lock_manager.locks.push(crate::state::lock_manager::Lock {
+
+    // Record the lock position, controlled by the beneficiary through the receipt
+    let lock_position = &mut ctx.accounts.lock_position;
+    lock_position.initialize(
+        ctx.bumps.lock_position,
+        ctx.accounts.user.key(),
+        lock_manager.key(),
+        nonce,
         amount,
-        lock_time: Timestamp::new(Clock::get()?.unix_timestamp),
+        Timestamp::new(current_time),
         lock_period,
-    });
+        cliff_period,
+        release_interval,
+        tier_id,
+        reward_multiplier_bps,
+        ctx.accounts.receipt_mint.key(),
+    )?;
+    ctx.accounts.lock_manager.update_position_weight(&mut ctx.accounts.lock_position)?;
+    ctx.accounts.voting_escrow.update_position_voting_power(&mut ctx.accounts.lock_position, current_time)?;
+
+    // Mint the position's receipt
+    let seeds = &[
+        LOCK_MANAGER_SEED,
+        &[ctx.bumps.lock_manager],
+    ];
+    let signer = &[&seeds[..]];
+    token::mint_to(ctx.accounts.mint_receipt_context().with_signer(signer), 1)?;
 
     // Update controller state
     ctx.accounts.controller.reload()?;
@@ -86,8 +195,13 @@ pub fn handler(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp)
     // Emit lock event
     emit!(LockEvent {
         user: *ctx.accounts.user.key,
+        beneficiary: ctx.accounts.beneficiary.key(),
+        lock_position: ctx.accounts.lock_position.key(),
         amount,
         lock_period,
+        tier_id,
+        reward_multiplier_bps,
+        receipt_mint: ctx.accounts.receipt_mint.key(),
     });
 
     Ok(())
@@ -96,6 +210,11 @@ pub fn handler(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp)
 #[event]
 pub struct LockEvent {
     pub user: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lock_position: Pubkey,
     pub amount: Amount,
     pub lock_period: Timestamp,
+    pub tier_id: u8,
+    pub reward_multiplier_bps: u16,
+    pub receipt_mint: Pubkey,
 }
\ No newline at end of file
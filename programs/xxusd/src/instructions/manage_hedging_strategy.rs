@@ -1,12 +1,26 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
+use crate::adapters::lending::{CpiLendingAdapter, LendingAdapter};
+use crate::adapters::swap::{swap_with_min_amount_out, CpiSwapAdapter};
 use crate::error::XxusdError;
-use crate::state::{controller::Controller, hedging_strategy::HedgingStrategy, Amount};
+use crate::events::HedgingStrategyEvent;
+use crate::instructions::cover_strategy_loss::cover_loss_from_insurance_fund;
+use crate::instructions::mark_strategy::emit_strategy_snapshot;
+use crate::instructions::top_up_insurance_fund::InsuranceFundDepositEvent;
+use crate::state::hedging_audit_log::{HedgingAuditLog, HedgingAuditLogEntry, HedgingOperationType, SWAP_PRICE_PRECISION};
+use crate::state::{controller::Controller, hedging_strategy::{HedgingStrategy, HedgingStrategyType}, insurance_fund::InsuranceFund, swap_program::SwapProgram, Amount, KaminoDepository};
+use crate::utils::calculate_lending_exchange_rate::{calculate_collateral_to_redeem, calculate_exchange_rate, calculate_underlying_amount};
+use crate::utils::calculate_strategy_pnl::{calculate_bps_of, calculate_withdrawal_pnl};
 use crate::utils::maths::{checked_add, checked_sub};
 
 pub const CONTROLLER_SEED: &[u8] = b"controller";
 pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const SWAP_PROGRAM_SEED: &[u8] = b"swap_program";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+pub const HEDGING_AUDIT_LOG_SEED: &[u8] = b"hedging_audit_log";
 
 #[derive(Accounts)]
 pub struct ManageHedgingStrategy<'info> {
@@ -23,29 +37,240 @@ pub struct ManageHedgingStrategy<'info> {
 
     #[account(
         mut,
-        seeds = [HEDGING_STRATEGY_SEED],
-        bump,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
     )]
     pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
 
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    /// Holds the collateral allocated to the strategy, only the strategy PDA can move it
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Takes its share of realized yield and covers realized losses
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED, collateral_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = insurance_fund,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [HEDGING_AUDIT_LOG_SEED, hedging_strategy.key().as_ref()],
+        bump = hedging_audit_log.bump,
+        has_one = hedging_strategy @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub hedging_audit_log: Box<Account<'info, HedgingAuditLog>>,
+
+    // Lending strategies only: the reserve the collateral is lent to, checked by `CpiLendingAdapter`
+
+    /// CHECK: the strategy's lending venue
+    #[account(
+        executable,
+        address = hedging_strategy.venue_program @XxusdError::InvalidLendingAccount,
+    )]
+    pub lending_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: owner and layout are checked by `CpiLendingAdapter`
+    #[account(address = hedging_strategy.venue_market @XxusdError::InvalidLendingAccount)]
+    pub lending_reserve: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
-    pub source_account: Box<Account<'info, TokenAccount>>,
+    pub reserve_liquidity_supply: Option<Box<Account<'info, TokenAccount>>>,
 
     #[account(mut)]
+    pub reserve_collateral_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// The strategy's reserve collateral tokens
+    #[account(
+        mut,
+        associated_token::mint = reserve_collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_collateral: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+#[derive(Accounts)]
+pub struct SwapAssets<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    #[account(
+        seeds = [SWAP_PROGRAM_SEED, amm_program.key().as_ref()],
+        bump = swap_program.bump,
+        has_one = controller,
+        constraint = swap_program.is_enabled @XxusdError::SwapProgramDisabled,
+    )]
+    pub swap_program: Box<Account<'info, SwapProgram>>,
+
+    #[account(
+        mut,
+        seeds = [HEDGING_AUDIT_LOG_SEED, hedging_strategy.key().as_ref()],
+        bump = hedging_audit_log.bump,
+        has_one = hedging_strategy @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub hedging_audit_log: Box<Account<'info, HedgingAuditLog>>,
+
+    /// CHECK: whitelisted through `swap_program`
+    #[account(executable)]
+    pub amm_program: UncheckedAccount<'info>,
+
+    pub source_mint: Box<Account<'info, Mint>>,
+
+    /// Every swap ends in the strategy's collateral, see `HedgingStrategy::is_swap_allowed`
+    #[account(
+        constraint = hedging_strategy.is_swap_allowed(&source_mint.key(), &destination_mint.key())
+            @XxusdError::InvalidCollateralMint,
+    )]
+    pub destination_mint: Box<Account<'info, Mint>>,
+
+    /// The strategy's vault of `source_mint`, only the strategy PDA can move it
+    #[account(
+        mut,
+        associated_token::mint = source_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub source_account: Box<Account<'info, TokenAccount>>,
+
+    /// The strategy's collateral vault
+    #[account(
+        mut,
+        associated_token::mint = destination_mint,
+        associated_token::authority = hedging_strategy,
+    )]
     pub destination_account: Box<Account<'info, TokenAccount>>,
 
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
 }
 
 impl<'info> ManageHedgingStrategy<'info> {
-    fn transfer_context(&self, from: &AccountInfo<'info>, to: &AccountInfo<'info>, authority: &AccountInfo<'info>) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+    fn deposit_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.depository_collateral.to_account_info(),
+            to: self.hedging_strategy_vault.to_account_info(),
+            authority: self.kamino_depository.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn withdraw_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
-            from: from.clone(),
-            to: to.clone(),
-            authority: authority.clone(),
+            from: self.hedging_strategy_vault.to_account_info(),
+            to: self.depository_collateral.to_account_info(),
+            authority: self.hedging_strategy.to_account_info(),
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
+
+    fn insurance_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.hedging_strategy_vault.to_account_info(),
+            to: self.insurance_fund_vault.to_account_info(),
+            authority: self.hedging_strategy.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn lending_adapter(&self) -> Result<CpiLendingAdapter<'_, 'info>> {
+        match (
+            &self.lending_program,
+            &self.lending_reserve,
+            &self.reserve_liquidity_supply,
+            &self.reserve_collateral_mint,
+            &self.hedging_strategy_collateral,
+        ) {
+            (Some(program), Some(reserve), Some(liquidity_supply), Some(collateral_mint), Some(user_collateral)) => {
+                let adapter = CpiLendingAdapter {
+                    program: program.as_ref(),
+                    reserve: reserve.as_ref(),
+                    liquidity_supply,
+                    collateral_mint,
+                    user_liquidity: self.hedging_strategy_vault.as_ref().as_ref(),
+                    user_collateral: user_collateral.as_ref().as_ref(),
+                    owner: self.hedging_strategy.as_ref().as_ref(),
+                    token_program: self.token_program.as_ref(),
+                };
+                adapter.validate(&self.collateral_mint.key())?;
+                Ok(adapter)
+            }
+            _ => err!(XxusdError::InvalidLendingAccount),
+        }
+    }
+
+    /// Records the reserve collateral held and the reserve's exchange rate after a CPI.
+    fn record_lending_position(&mut self) -> Result<()> {
+        let (Some(liquidity_supply), Some(collateral_mint), Some(user_collateral)) = (
+            &mut self.reserve_liquidity_supply,
+            &mut self.reserve_collateral_mint,
+            &mut self.hedging_strategy_collateral,
+        ) else {
+            return err!(XxusdError::InvalidLendingAccount);
+        };
+        liquidity_supply.reload()?;
+        collateral_mint.reload()?;
+        user_collateral.reload()?;
+
+        let exchange_rate = calculate_exchange_rate(liquidity_supply.amount, collateral_mint.supply)?;
+        let lending_collateral_amount = user_collateral.amount;
+        self.hedging_strategy.record_exchange_rate(
+            lending_collateral_amount,
+            exchange_rate,
+            Clock::get()?.unix_timestamp,
+        );
+        Ok(())
+    }
+
+    fn is_lending(&self) -> bool {
+        self.hedging_strategy.strategy_type == HedgingStrategyType::Lending
+    }
 }
 
 pub fn handler(ctx: Context<ManageHedgingStrategy>, amount: Amount, is_deposit: bool) -> Result<()> {
@@ -59,31 +284,55 @@ pub fn handler(ctx: Context<ManageHedgingStrategy>, amount: Amount, is_deposit:
 }
 
 fn deposit_to_lending_platform(ctx: Context<ManageHedgingStrategy>, amount: Amount) -> Result<()> {
-    // Check if source account has enough balance
+    require!(ctx.accounts.hedging_strategy.is_enabled, XxusdError::HedgingStrategyDisabled);
+    require!(!ctx.accounts.hedging_strategy.is_withdraw_only, XxusdError::HedgingStrategyWithdrawOnly);
+
+    // Check if the depository has enough collateral
     require!(
-        ctx.accounts.source_account.amount >= amount.value(),
+        ctx.accounts.depository_collateral.amount >= amount.value(),
         XxusdError::InsufficientFunds
     );
 
     // Get the current deposited amount
     let current_deposited_amount = ctx.accounts.hedging_strategy.get_deposited_amount();
 
-    // Transfer tokens to lending platform
-    token::transfer(
-        ctx.accounts.transfer_context(
-            &ctx.accounts.source_account.to_account_info(),
-            &ctx.accounts.destination_account.to_account_info(),
-            &ctx.accounts.authority.to_account_info()
-        ),
-        amount.value()
-    )?;
+    let new_deposited_amount = checked_add(current_deposited_amount, amount)?;
+    require!(
+        new_deposited_amount.value() <= ctx.accounts.hedging_strategy.max_allocation,
+        XxusdError::MaxAllocationExceeded
+    );
+
+    // Move collateral from the depository into the strategy vault
+    let collateral_mint = ctx.accounts.collateral_mint.key();
+    let seeds = &[
+        KAMINO_DEPOSITORY_SEED,
+        collateral_mint.as_ref(),
+        &[ctx.accounts.kamino_depository.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.deposit_transfer_context().with_signer(signer), amount.value())?;
+
+    // Lend it out, the strategy receives the reserve's collateral tokens
+    if ctx.accounts.is_lending() {
+        let seeds = &[
+            HEDGING_STRATEGY_SEED,
+            &[ctx.accounts.hedging_strategy.strategy_id],
+            &[ctx.accounts.hedging_strategy.bump],
+        ];
+        let signer = &[&seeds[..]];
+        ctx.accounts.lending_adapter()?.deposit(amount.value(), signer)?;
+        ctx.accounts.record_lending_position()?;
+    }
 
     // Update hedging strategy state
-    let new_deposited_amount = checked_add(current_deposited_amount, amount)?;
     ctx.accounts.hedging_strategy.set_deposited_amount(new_deposited_amount);
+    mark_hedging_strategy(ctx.accounts)?;
+    record_strategy_operation(ctx.accounts, HedgingOperationType::Deposit, amount.value())?;
 
     // Emit deposit event
     emit!(DepositEvent {
+        strategy_id: ctx.accounts.hedging_strategy.strategy_id,
+        collateral_mint,
         amount,
         new_total_deposited: new_deposited_amount,
     });
@@ -91,32 +340,92 @@ fn deposit_to_lending_platform(ctx: Context<ManageHedgingStrategy>, amount: Amou
     Ok(())
 }
 
-fn withdraw_from_lending_platform(ctx: Context<ManageHedgingStrategy>, amount: Amount) -> Result<()> {
+fn withdraw_from_lending_platform(mut ctx: Context<ManageHedgingStrategy>, amount: Amount) -> Result<()> {
     // Get the current deposited amount
     let current_deposited_amount = ctx.accounts.hedging_strategy.get_deposited_amount();
+    let is_lending = ctx.accounts.is_lending();
+
+    // Ensure we have enough deposited to withdraw. Lent collateral may have earned interest
+    // or lost value, so a lending strategy withdraws against its assets at the current
+    // exchange rate and realizes the difference with its principal.
+    let (principal_amount, realized_pnl) = if is_lending {
+        ctx.accounts.record_lending_position()?;
+        let assets_amount = ctx.accounts.hedging_strategy_vault.amount
+            .checked_add(ctx.accounts.hedging_strategy.get_lending_underlying_amount()?)
+            .ok_or(XxusdError::MathOverflow)?;
+        require!(assets_amount >= amount.value(), XxusdError::InsufficientFunds);
+        calculate_withdrawal_pnl(amount.value(), assets_amount, current_deposited_amount.value())?
+    } else {
+        require!(
+            current_deposited_amount.value() >= amount.value(),
+            XxusdError::InsufficientFunds
+        );
+        (amount.value(), 0)
+    };
+
+    let seeds = &[
+        HEDGING_STRATEGY_SEED,
+        &[ctx.accounts.hedging_strategy.strategy_id],
+        &[ctx.accounts.hedging_strategy.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Redeem just enough reserve collateral to cover what the vault is missing
+    let vault_amount = ctx.accounts.hedging_strategy_vault.amount;
+    if is_lending && vault_amount < amount.value() {
+        redeem_from_lending_platform(&mut ctx, amount.value() - vault_amount, signer)?;
+    }
 
-    // Ensure we have enough deposited to withdraw
     require!(
-        current_deposited_amount.value() >= amount.value(),
+        ctx.accounts.hedging_strategy_vault.amount >= amount.value(),
         XxusdError::InsufficientFunds
     );
 
-    // Transfer tokens from lending platform
+    // The insurance fund takes its share of realized yield
+    let insurance_amount = if realized_pnl > 0 {
+        calculate_bps_of(realized_pnl.unsigned_abs(), ctx.accounts.insurance_fund.yield_share_bps)?
+    } else {
+        0
+    };
+    if insurance_amount > 0 {
+        token::transfer(ctx.accounts.insurance_transfer_context().with_signer(signer), insurance_amount)?;
+        ctx.accounts.insurance_fund.record_deposit(insurance_amount)?;
+        ctx.accounts.insurance_fund_vault.reload()?;
+        emit!(InsuranceFundDepositEvent {
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            strategy_id: Some(ctx.accounts.hedging_strategy.strategy_id),
+            amount: insurance_amount,
+            balance: ctx.accounts.insurance_fund_vault.amount,
+        });
+    }
+
+    // Return the rest from the strategy vault to the depository, signed by the strategy
     token::transfer(
-        ctx.accounts.transfer_context(
-            &ctx.accounts.source_account.to_account_info(),
-            &ctx.accounts.destination_account.to_account_info(),
-            &ctx.accounts.authority.to_account_info()
-        ),
-        amount.value()
+        ctx.accounts.withdraw_transfer_context().with_signer(signer),
+        amount.value() - insurance_amount,
     )?;
 
-    // Update hedging strategy state
-    let new_deposited_amount = checked_sub(current_deposited_amount, amount)?;
+    // Update hedging strategy state, realized losses are covered by the insurance fund first
+    let new_deposited_amount = checked_sub(current_deposited_amount, Amount::new(principal_amount))?;
+    ctx.accounts.hedging_strategy.add_realized_pnl(realized_pnl)?;
     ctx.accounts.hedging_strategy.set_deposited_amount(new_deposited_amount);
+    if ctx.accounts.hedging_strategy.uncovered_loss > 0 {
+        let accounts = &mut *ctx.accounts;
+        cover_loss_from_insurance_fund(
+            &mut accounts.hedging_strategy,
+            &mut accounts.insurance_fund,
+            &mut accounts.insurance_fund_vault,
+            &accounts.depository_collateral,
+            &accounts.token_program,
+        )?;
+    }
+    mark_hedging_strategy(ctx.accounts)?;
+    record_strategy_operation(ctx.accounts, HedgingOperationType::Withdraw, amount.value())?;
 
     // Emit withdraw event
     emit!(WithdrawEvent {
+        strategy_id: ctx.accounts.hedging_strategy.strategy_id,
+        collateral_mint: ctx.accounts.collateral_mint.key(),
         amount,
         new_total_deposited: new_deposited_amount,
     });
@@ -124,14 +433,143 @@ fn withdraw_from_lending_platform(ctx: Context<ManageHedgingStrategy>, amount: A
     Ok(())
 }
 
-pub fn swap_assets(_ctx: Context<ManageHedgingStrategy>, amount_in: Amount, min_amount_out: Amount) -> Result<()> {
-    // Here you would implement the logic to swap assets
-    // This might involve calling an external DEX or AMM
+fn mark_hedging_strategy(accounts: &mut ManageHedgingStrategy) -> Result<()> {
+    accounts.hedging_strategy_vault.reload()?;
+    let vault_amount = accounts.hedging_strategy_vault.amount;
+    accounts.hedging_strategy.mark(vault_amount, Clock::get()?.unix_timestamp)?;
+    emit_strategy_snapshot(&accounts.hedging_strategy, vault_amount);
+    Ok(())
+}
+
+fn record_strategy_operation(
+    accounts: &mut ManageHedgingStrategy,
+    operation_type: HedgingOperationType,
+    amount: u64,
+) -> Result<()> {
+    let hedging_strategy = &accounts.hedging_strategy;
+    let price = match hedging_strategy.strategy_type {
+        HedgingStrategyType::Lending => {
+            u64::try_from(hedging_strategy.exchange_rate).map_err(|_| XxusdError::MathOverflow)?
+        }
+        HedgingStrategyType::PerpShort => hedging_strategy.mark_price,
+    };
+    append_audit_log_entry(
+        &mut accounts.hedging_audit_log,
+        &accounts.controller,
+        operation_type,
+        amount,
+        price,
+        accounts.authority.key(),
+    )
+}
+
+/// Records an operator action in the strategy's audit log and emits it.
+fn append_audit_log_entry(
+    hedging_audit_log: &mut HedgingAuditLog,
+    controller: &Account<Controller>,
+    operation_type: HedgingOperationType,
+    amount: u64,
+    price: u64,
+    signer: Pubkey,
+) -> Result<()> {
+    hedging_audit_log.append(HedgingAuditLogEntry {
+        operation_type,
+        amount,
+        price,
+        slot: Clock::get()?.slot,
+        signer,
+    })?;
+
+    emit!(HedgingStrategyEvent {
+        version: controller.version,
+        controller: controller.key(),
+        operation_type: operation_type as u8,
+        amount,
+    });
+    Ok(())
+}
+
+fn redeem_from_lending_platform(
+    ctx: &mut Context<ManageHedgingStrategy>,
+    liquidity_amount: u64,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let adapter = ctx.accounts.lending_adapter()?;
+    let exchange_rate = adapter.exchange_rate()?;
+    let lending_collateral_amount = ctx.accounts.hedging_strategy_collateral
+        .as_ref()
+        .map(|account| account.amount)
+        .ok_or(XxusdError::InvalidLendingAccount)?;
+    require!(
+        calculate_underlying_amount(lending_collateral_amount, exchange_rate)? >= liquidity_amount,
+        XxusdError::InsufficientFunds
+    );
+
+    let collateral_amount = calculate_collateral_to_redeem(liquidity_amount, exchange_rate)?
+        .min(lending_collateral_amount);
+    adapter.redeem(collateral_amount, signer)?;
+
+    ctx.accounts.hedging_strategy_vault.reload()?;
+    ctx.accounts.record_lending_position()
+}
+
+/// Swaps another vault of the strategy into its collateral vault. Admin only, the strategy
+/// PDA signs the swap so the tokens never leave program custody.
+pub fn swap_assets<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapAssets<'info>>,
+    amount_in: Amount,
+    min_amount_out: Amount,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    require!(amount_in.value() > 0, XxusdError::InvalidAmount);
+    require!(
+        ctx.accounts.source_account.amount >= amount_in.value(),
+        XxusdError::InsufficientFunds
+    );
+
+    // The AMM instruction and its accounts come from the client, see `CpiSwapAdapter`. The
+    // strategy PDA owns both sides and signs for the source.
+    let seeds = &[
+        HEDGING_STRATEGY_SEED,
+        &[ctx.accounts.hedging_strategy.strategy_id],
+        &[ctx.accounts.hedging_strategy.bump],
+    ];
+    let signer = &[&seeds[..]];
+    let adapter = CpiSwapAdapter {
+        program: &ctx.accounts.amm_program.to_account_info(),
+        authority: &ctx.accounts.hedging_strategy.to_account_info(),
+        accounts: ctx.remaining_accounts,
+        data: swap_data,
+        swap_accounts: [ctx.accounts.source_account.key(), ctx.accounts.destination_account.key()],
+    };
+    let swap_result = swap_with_min_amount_out(
+        &adapter,
+        &mut ctx.accounts.source_account,
+        &mut ctx.accounts.destination_account,
+        amount_in.value(),
+        min_amount_out.value(),
+        signer,
+    )?;
+
+    let price = u128::from(swap_result.amount_out)
+        .checked_mul(SWAP_PRICE_PRECISION)
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(swap_result.amount_in.max(1));
+    let price = u64::try_from(price).map_err(|_| XxusdError::MathOverflow)?;
+    append_audit_log_entry(
+        &mut ctx.accounts.hedging_audit_log,
+        &ctx.accounts.controller,
+        HedgingOperationType::Swap,
+        swap_result.amount_in,
+        price,
+        ctx.accounts.authority.key(),
+    )?;
 
-    // For now, we'll just emit an event
     emit!(SwapEvent {
-        amount_in,
+        amm_program: ctx.accounts.amm_program.key(),
+        amount_in: Amount::new(swap_result.amount_in),
         min_amount_out,
+        amount_out: Amount::new(swap_result.amount_out),
     });
 
     Ok(())
@@ -139,18 +577,24 @@ pub fn swap_assets(_ctx: Context<ManageHedgingStrategy>, amount_in: Amount, min_
 
 #[event]
 pub struct DepositEvent {
+    pub strategy_id: u8,
+    pub collateral_mint: Pubkey,
     pub amount: Amount,
     pub new_total_deposited: Amount,
 }
 
 #[event]
 pub struct WithdrawEvent {
+    pub strategy_id: u8,
+    pub collateral_mint: Pubkey,
     pub amount: Amount,
     pub new_total_deposited: Amount,
 }
 
 #[event]
 pub struct SwapEvent {
+    pub amm_program: Pubkey,
     pub amount_in: Amount,
     pub min_amount_out: Amount,
+    pub amount_out: Amount,
 }
\ No newline at end of file
@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::adapters::lending::{CpiLendingAdapter, LendingAdapter};
+use crate::adapters::perp::{CpiPerpAdapter, PerpAdapter};
+use crate::error::XxusdError;
+use crate::state::hedging_strategy::{HedgingStrategy, HedgingStrategyType};
+
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+
+/// Permissionless: every venue account is checked against the strategy before it is read.
+#[derive(Accounts)]
+pub struct MarkStrategy<'info> {
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the strategy's lending or perp venue
+    #[account(
+        executable,
+        address = hedging_strategy.venue_program @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub venue_program: UncheckedAccount<'info>,
+
+    /// CHECK: the lending reserve or perp market, checked by the adapters
+    #[account(address = hedging_strategy.venue_market @XxusdError::InvalidHedgingStrategy)]
+    pub venue_market: UncheckedAccount<'info>,
+
+    // Lending strategies only
+
+    pub reserve_liquidity_supply: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub reserve_collateral_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(
+        associated_token::mint = reserve_collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_collateral: Option<Box<Account<'info, TokenAccount>>>,
+
+    // Perp strategies only
+
+    /// CHECK: owner, market and position owner are checked by `CpiPerpAdapter`
+    pub perp_position: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> MarkStrategy<'info> {
+    fn mark_lending(&mut self, current_time: i64) -> Result<()> {
+        let (Some(liquidity_supply), Some(collateral_mint), Some(user_collateral)) = (
+            &self.reserve_liquidity_supply,
+            &self.reserve_collateral_mint,
+            &self.hedging_strategy_collateral,
+        ) else {
+            return err!(XxusdError::InvalidLendingAccount);
+        };
+
+        let adapter = CpiLendingAdapter {
+            program: self.venue_program.as_ref(),
+            reserve: self.venue_market.as_ref(),
+            liquidity_supply,
+            collateral_mint,
+            user_liquidity: self.hedging_strategy_vault.as_ref().as_ref(),
+            user_collateral: user_collateral.as_ref().as_ref(),
+            owner: self.hedging_strategy.as_ref().as_ref(),
+            token_program: self.token_program.as_ref(),
+        };
+        adapter.validate(&self.collateral_mint.key())?;
+        let exchange_rate = adapter.exchange_rate()?;
+
+        let lending_collateral_amount = user_collateral.amount;
+        self.hedging_strategy.record_exchange_rate(lending_collateral_amount, exchange_rate, current_time);
+        Ok(())
+    }
+
+    fn mark_perp(&mut self) -> Result<()> {
+        let Some(perp_position) = &self.perp_position else {
+            return err!(XxusdError::InvalidPerpAccount);
+        };
+
+        let adapter = CpiPerpAdapter {
+            program: self.venue_program.as_ref(),
+            market: self.venue_market.as_ref(),
+            position: perp_position.as_ref(),
+            owner: self.hedging_strategy.as_ref().as_ref(),
+            margin: None,
+        };
+        let position = adapter.position()?;
+        require!(position.size <= 0, XxusdError::InvalidPerpAccount);
+        let mark_price = adapter.mark_price()?;
+
+        let hedging_strategy = &mut self.hedging_strategy;
+        hedging_strategy.position_size = position.size.unsigned_abs();
+        hedging_strategy.entry_price = position.entry_price;
+        hedging_strategy.funding_paid = position.funding_paid;
+        hedging_strategy.mark_price = mark_price;
+        Ok(())
+    }
+}
+
+/// Refreshes the strategy's venue state and marks its PnL to market.
+pub fn handler(ctx: Context<MarkStrategy>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    match ctx.accounts.hedging_strategy.strategy_type {
+        HedgingStrategyType::Lending => ctx.accounts.mark_lending(current_time)?,
+        HedgingStrategyType::PerpShort => ctx.accounts.mark_perp()?,
+    }
+
+    let vault_amount = ctx.accounts.hedging_strategy_vault.amount;
+    ctx.accounts.hedging_strategy.mark(vault_amount, current_time)?;
+    emit_strategy_snapshot(&ctx.accounts.hedging_strategy, vault_amount);
+
+    Ok(())
+}
+
+/// Emitted whenever a strategy is marked, enough to rebuild its performance over time.
+pub fn emit_strategy_snapshot(hedging_strategy: &HedgingStrategy, vault_amount: u64) {
+    emit!(StrategySnapshotEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        timestamp: hedging_strategy.last_marked_at,
+        deposited_amount: hedging_strategy.deposited_amount,
+        vault_amount,
+        lending_collateral_amount: hedging_strategy.lending_collateral_amount,
+        exchange_rate: hedging_strategy.exchange_rate,
+        position_size: hedging_strategy.position_size,
+        entry_price: hedging_strategy.entry_price,
+        mark_price: hedging_strategy.mark_price,
+        realized_pnl: hedging_strategy.realized_pnl,
+        unrealized_pnl: hedging_strategy.unrealized_pnl,
+        accrued_yield: hedging_strategy.accrued_yield,
+        fees_paid: hedging_strategy.funding_paid,
+        high_water_mark: hedging_strategy.high_water_mark,
+        is_withdraw_only: hedging_strategy.is_withdraw_only,
+        uncovered_loss: hedging_strategy.uncovered_loss,
+    });
+}
+
+#[event]
+pub struct StrategySnapshotEvent {
+    pub strategy_id: u8,
+    pub timestamp: i64,
+    pub deposited_amount: u64,
+    pub vault_amount: u64,
+    pub lending_collateral_amount: u64,
+    pub exchange_rate: u128,
+    pub position_size: u64,
+    pub entry_price: u64,
+    pub mark_price: u64,
+    // PnL, in collateral base units
+    pub realized_pnl: i64,
+    pub unrealized_pnl: i64,
+    pub accrued_yield: u64,
+    /// Perp funding paid, negative when received.
+    pub fees_paid: i64,
+    pub high_water_mark: i64,
+    pub is_withdraw_only: bool,
+    pub uncovered_loss: u64,
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::error::XxusdError;
+use crate::events::MigrateControllerEvent;
+use crate::state::{Controller, ControllerV0, CONTROLLER_VERSION};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+
+#[derive(Accounts)]
+pub struct MigrateController<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: deserialized in the handler, the layout depends on its version
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub controller: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites the controller in the current layout, growing the account when needed.
+/// Running it on an up to date controller changes nothing.
+pub fn handler(ctx: Context<MigrateController>) -> Result<()> {
+    let controller_info = ctx.accounts.controller.to_account_info();
+
+    let (controller, previous_version) = {
+        let data = controller_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == Controller::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        // Every layout starts with the V0 fields, the version byte follows them
+        let mut fields: &[u8] = &data[8..];
+        let legacy_controller = ControllerV0::deserialize(&mut fields)?;
+        let previous_version = fields.first().copied().unwrap_or(0);
+        (legacy_controller, previous_version)
+    };
+    require_keys_eq!(controller.authority, ctx.accounts.authority.key(), ErrorCode::ConstraintHasOne);
+    require!(previous_version <= CONTROLLER_VERSION, XxusdError::UnsupportedControllerVersion);
+
+    if previous_version < CONTROLLER_VERSION {
+        let controller = Controller::from(controller);
+        let space = Controller::space(controller.max_products);
+
+        if controller_info.data_len() < space {
+            let required_lamports = Rent::get()?
+                .minimum_balance(space)
+                .saturating_sub(controller_info.lamports());
+            if required_lamports > 0 {
+                let cpi_accounts = Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: controller_info.clone(),
+                };
+                system_program::transfer(
+                    CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts),
+                    required_lamports,
+                )?;
+            }
+            controller_info.realloc(space, false)?;
+        }
+
+        let mut data = controller_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data[..];
+        controller.try_serialize(&mut writer)?;
+    }
+
+    emit!(MigrateControllerEvent {
+        version: CONTROLLER_VERSION,
+        controller: controller_info.key(),
+        previous_version,
+    });
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use anchor_spl::associated_token::AssociatedToken;
 
 use crate::error::XxusdError;
 use crate::state::{Controller, KaminoDepository, Amount};
-use crate::{CONTROLLER_NAMESPACE, JUPSOL_MINT_PUBKEY};
+use crate::CONTROLLER_NAMESPACE;
 use crate::utils::maths::checked_add;
 
 pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
@@ -28,9 +28,6 @@ pub struct MintInstruction<'info> {
     #[account(mut)]
     pub redeemable_mint: Box<Account<'info, Mint>>,
 
-    #[account(
-        constraint = collateral_mint.key() == JUPSOL_MINT_PUBKEY @XxusdError::InvalidCollateralMint
-    )]
     pub collateral_mint: Box<Account<'info, Mint>>,
 
     #[account(
@@ -49,13 +46,22 @@ pub struct MintInstruction<'info> {
 
     #[account(
         mut,
-        seeds = [KAMINO_DEPOSITORY_SEED],
-        bump,
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
         has_one = controller @XxusdError::InvalidController,
         has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+        constraint = kamino_depository.is_enabled @XxusdError::CollateralMintDisabled,
+        constraint = kamino_depository.collateral_mint_decimals == collateral_mint.decimals @XxusdError::InvalidCollateralMintDecimals,
     )]
     pub kamino_depository: Box<Account<'info, KaminoDepository>>,
 
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -66,7 +72,7 @@ impl<'info> MintInstruction<'info> {
     fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
             from: self.user_collateral.to_account_info(),
-            to: self.kamino_depository.to_account_info(),
+            to: self.depository_collateral.to_account_info(),
             authority: self.user.to_account_info(),
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
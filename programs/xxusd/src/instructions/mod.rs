@@ -7,20 +7,110 @@ pub mod manage_product_price;
 pub mod manage_hedging_strategy;
 pub mod freeze_program;
 pub mod edit_controller;
+pub mod register_collateral_mint;
+pub mod edit_collateral_mint;
+pub mod register_term_depository;
+pub mod edit_term_depository;
+pub mod allocate_term_deposit;
+pub mod queue_redemption;
+pub mod claim_queued_redemption;
+pub mod initialize_lock_manager;
+pub mod initialize_lock_tier;
+pub mod edit_lock_tier;
+pub mod edit_lock_manager;
+pub mod early_release;
+pub mod increase_lock_amount;
+pub mod extend_lock_period;
+pub mod deposit_lock_rewards;
+pub mod claim_lock_rewards;
+pub mod initialize_staking_vault;
+pub mod edit_staking_vault;
+pub mod deposit_staking_vault;
+pub mod add_staking_yield;
+pub mod withdraw_staking_vault;
+pub mod request_staking_withdrawal;
+pub mod claim_staking_withdrawal;
+pub mod initialize_voting_escrow;
+pub mod checkpoint_voting_power;
+pub mod get_voting_power;
+pub mod set_lock_delegate;
+pub mod create_vesting_grant;
+pub mod release_vesting_grant;
+pub mod revoke_vesting_grant;
+pub mod resize_controller;
+pub mod migrate_controller;
+pub mod register_swap_program;
+pub mod edit_swap_program;
+pub mod initialize_hedging_strategy;
+pub mod edit_hedging_strategy;
+pub mod rebalance_hedge;
+pub mod mark_strategy;
+pub mod check_strategy_risk;
+pub mod initialize_insurance_fund;
+pub mod edit_insurance_fund;
+pub mod top_up_insurance_fund;
+pub mod cover_strategy_loss;
+pub mod adjust_hedge_ratio;
+pub mod initialize_hedging_audit_log;
 
 use anchor_lang::prelude::*;
-use crate::state::{Amount, Timestamp};
+use crate::state::Amount;
 
 // 具體導入
 pub use initialize_controller::{InitializeController, InitializeControllerParams, handler as initialize_controller_handler};
 pub use mint::{MintInstruction, handler as mint_handler};
 pub use redeem::{Redeem, handler as redeem_handler};
-pub use lock_xxusd::{LockXxusd, handler as lock_xxusd_handler};
+pub use lock_xxusd::{LockXxusd, LockXxusdParams, handler as lock_xxusd_handler};
 pub use release_xxusd::{ReleaseXxusd, handler as release_xxusd_handler};
 pub use manage_product_price::{ManageProductPrice, handler as manage_product_price_handler};
-pub use manage_hedging_strategy::{ManageHedgingStrategy, handler as manage_hedging_strategy_handler};
+pub use manage_hedging_strategy::{ManageHedgingStrategy, SwapAssets, handler as manage_hedging_strategy_handler, swap_assets as swap_assets_handler};
 pub use freeze_program::{FreezeProgram, handler as freeze_program_handler};
 pub use edit_controller::{EditController, handler as edit_controller_handler};
+pub use register_collateral_mint::{RegisterCollateralMint, RegisterCollateralMintParams, handler as register_collateral_mint_handler};
+pub use edit_collateral_mint::{EditCollateralMint, EditCollateralMintParams, handler as edit_collateral_mint_handler};
+pub use register_term_depository::{RegisterTermDepository, RegisterTermDepositoryParams, handler as register_term_depository_handler};
+pub use edit_term_depository::{EditTermDepository, EditTermDepositoryParams, handler as edit_term_depository_handler};
+pub use allocate_term_deposit::{AllocateTermDeposit, handler as allocate_term_deposit_handler};
+pub use queue_redemption::{QueueRedemption, handler as queue_redemption_handler};
+pub use claim_queued_redemption::{ClaimQueuedRedemption, handler as claim_queued_redemption_handler};
+pub use initialize_lock_manager::{InitializeLockManager, handler as initialize_lock_manager_handler};
+pub use initialize_lock_tier::{InitializeLockTier, InitializeLockTierParams, handler as initialize_lock_tier_handler};
+pub use edit_lock_tier::{EditLockTier, EditLockTierParams, handler as edit_lock_tier_handler};
+pub use edit_lock_manager::{EditLockManager, EditLockManagerParams, handler as edit_lock_manager_handler};
+pub use early_release::{EarlyRelease, handler as early_release_handler};
+pub use increase_lock_amount::{IncreaseLockAmount, IncreaseLockAmountEvent, handler as increase_lock_amount_handler};
+pub use extend_lock_period::{ExtendLockPeriod, ExtendLockPeriodEvent, handler as extend_lock_period_handler};
+pub use deposit_lock_rewards::{DepositLockRewards, DepositLockRewardsEvent, handler as deposit_lock_rewards_handler};
+pub use claim_lock_rewards::{ClaimLockRewards, ClaimLockRewardsEvent, handler as claim_lock_rewards_handler};
+pub use initialize_staking_vault::{InitializeStakingVault, InitializeStakingVaultEvent, handler as initialize_staking_vault_handler};
+pub use edit_staking_vault::{EditStakingVault, EditStakingVaultParams, EditStakingVaultEvent, handler as edit_staking_vault_handler};
+pub use deposit_staking_vault::{DepositStakingVault, DepositStakingVaultEvent, handler as deposit_staking_vault_handler};
+pub use add_staking_yield::{AddStakingYield, AddStakingYieldEvent, handler as add_staking_yield_handler};
+pub use withdraw_staking_vault::{WithdrawStakingVault, WithdrawStakingVaultEvent, handler as withdraw_staking_vault_handler};
+pub use request_staking_withdrawal::{RequestStakingWithdrawal, RequestStakingWithdrawalEvent, handler as request_staking_withdrawal_handler};
+pub use claim_staking_withdrawal::{ClaimStakingWithdrawal, ClaimStakingWithdrawalEvent, handler as claim_staking_withdrawal_handler};
+pub use initialize_voting_escrow::{InitializeVotingEscrow, handler as initialize_voting_escrow_handler};
+pub use checkpoint_voting_power::{CheckpointVotingPower, VotingPowerCheckpointEvent, handler as checkpoint_voting_power_handler};
+pub use get_voting_power::{GetVotingPower, handler as get_voting_power_handler};
+pub use set_lock_delegate::{SetLockDelegate, SetLockDelegateEvent, handler as set_lock_delegate_handler};
+pub use create_vesting_grant::{CreateVestingGrant, CreateVestingGrantParams, CreateVestingGrantEvent, handler as create_vesting_grant_handler};
+pub use release_vesting_grant::{ReleaseVestingGrant, ReleaseVestingGrantEvent, handler as release_vesting_grant_handler};
+pub use revoke_vesting_grant::{RevokeVestingGrant, RevokeVestingGrantEvent, handler as revoke_vesting_grant_handler};
+pub use resize_controller::{ResizeController, ResizeControllerEvent, handler as resize_controller_handler};
+pub use migrate_controller::{MigrateController, handler as migrate_controller_handler};
+pub use register_swap_program::{RegisterSwapProgram, SetSwapProgramEvent, handler as register_swap_program_handler};
+pub use edit_swap_program::{EditSwapProgram, handler as edit_swap_program_handler};
+pub use initialize_hedging_strategy::{InitializeHedgingStrategy, InitializeHedgingStrategyParams, SetHedgingStrategyEvent, handler as initialize_hedging_strategy_handler};
+pub use edit_hedging_strategy::{EditHedgingStrategy, EditHedgingStrategyParams, handler as edit_hedging_strategy_handler};
+pub use rebalance_hedge::{RebalanceHedge, RebalanceHedgeEvent, handler as rebalance_hedge_handler};
+pub use mark_strategy::{MarkStrategy, StrategySnapshotEvent, handler as mark_strategy_handler};
+pub use check_strategy_risk::{CheckStrategyRisk, StrategyRiskAlertEvent, handler as check_strategy_risk_handler};
+pub use initialize_insurance_fund::{InitializeInsuranceFund, SetInsuranceFundEvent, handler as initialize_insurance_fund_handler};
+pub use edit_insurance_fund::{EditInsuranceFund, handler as edit_insurance_fund_handler};
+pub use top_up_insurance_fund::{TopUpInsuranceFund, InsuranceFundDepositEvent, handler as top_up_insurance_fund_handler};
+pub use cover_strategy_loss::{CoverStrategyLoss, InsuranceFundDrawEvent, handler as cover_strategy_loss_handler};
+pub use adjust_hedge_ratio::{AdjustHedgeRatio, HedgeRatioAdjustmentEvent, handler as adjust_hedge_ratio_handler};
+pub use initialize_hedging_audit_log::{InitializeHedgingAuditLog, InitializeHedgingAuditLogEvent, handler as initialize_hedging_audit_log_handler};
 
 pub fn initialize_controller(ctx: Context<InitializeController>, params: InitializeControllerParams) -> Result<()> {
     initialize_controller::handler(ctx, params)
@@ -34,8 +124,8 @@ pub fn redeem(ctx: Context<Redeem>, redeemable_amount: Amount) -> Result<()> {
     redeem::handler(ctx, redeemable_amount)
 }
 
-pub fn lock_xxusd(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp) -> Result<()> {
-    lock_xxusd::handler(ctx, amount, lock_period)
+pub fn lock_xxusd(ctx: Context<LockXxusd>, params: LockXxusdParams) -> Result<()> {
+    lock_xxusd::handler(ctx, params)
 }
 
 pub fn release_xxusd(ctx: Context<ReleaseXxusd>) -> Result<()> {
@@ -56,4 +146,193 @@ pub fn freeze_program(ctx: Context<FreezeProgram>, freeze: bool) -> Result<()> {
 
 pub fn edit_controller(ctx: Context<EditController>, new_authority: Option<Pubkey>) -> Result<()> {
     edit_controller::handler(ctx, new_authority)
+}
+
+pub fn register_collateral_mint(ctx: Context<RegisterCollateralMint>, params: RegisterCollateralMintParams) -> Result<()> {
+    register_collateral_mint::handler(ctx, params)
+}
+
+pub fn edit_collateral_mint(ctx: Context<EditCollateralMint>, params: EditCollateralMintParams) -> Result<()> {
+    edit_collateral_mint::handler(ctx, params)
+}
+
+pub fn register_term_depository(ctx: Context<RegisterTermDepository>, params: RegisterTermDepositoryParams) -> Result<()> {
+    register_term_depository::handler(ctx, params)
+}
+
+pub fn edit_term_depository(ctx: Context<EditTermDepository>, params: EditTermDepositoryParams) -> Result<()> {
+    edit_term_depository::handler(ctx, params)
+}
+
+pub fn allocate_term_deposit(ctx: Context<AllocateTermDeposit>, amount: Amount) -> Result<()> {
+    allocate_term_deposit::handler(ctx, amount)
+}
+
+pub fn queue_redemption(ctx: Context<QueueRedemption>, redeemable_amount: Amount, nonce: u64) -> Result<()> {
+    queue_redemption::handler(ctx, redeemable_amount, nonce)
+}
+
+pub fn claim_queued_redemption(ctx: Context<ClaimQueuedRedemption>) -> Result<()> {
+    claim_queued_redemption::handler(ctx)
+}
+
+pub fn initialize_lock_manager(ctx: Context<InitializeLockManager>) -> Result<()> {
+    initialize_lock_manager::handler(ctx)
+}
+
+pub fn initialize_lock_tier(ctx: Context<InitializeLockTier>, params: InitializeLockTierParams) -> Result<()> {
+    initialize_lock_tier::handler(ctx, params)
+}
+
+pub fn edit_lock_tier(ctx: Context<EditLockTier>, params: EditLockTierParams) -> Result<()> {
+    edit_lock_tier::handler(ctx, params)
+}
+
+pub fn edit_lock_manager(ctx: Context<EditLockManager>, params: EditLockManagerParams) -> Result<()> {
+    edit_lock_manager::handler(ctx, params)
+}
+
+pub fn early_release(ctx: Context<EarlyRelease>) -> Result<()> {
+    early_release::handler(ctx)
+}
+
+pub fn increase_lock_amount(ctx: Context<IncreaseLockAmount>, amount: Amount) -> Result<()> {
+    increase_lock_amount::handler(ctx, amount)
+}
+
+pub fn extend_lock_period(ctx: Context<ExtendLockPeriod>, tier_id: u8) -> Result<()> {
+    extend_lock_period::handler(ctx, tier_id)
+}
+
+pub fn deposit_lock_rewards(ctx: Context<DepositLockRewards>, amount: Amount) -> Result<()> {
+    deposit_lock_rewards::handler(ctx, amount)
+}
+
+pub fn claim_lock_rewards(ctx: Context<ClaimLockRewards>) -> Result<()> {
+    claim_lock_rewards::handler(ctx)
+}
+
+pub fn initialize_staking_vault(ctx: Context<InitializeStakingVault>, cooldown_period: i64) -> Result<()> {
+    initialize_staking_vault::handler(ctx, cooldown_period)
+}
+
+pub fn edit_staking_vault(ctx: Context<EditStakingVault>, params: EditStakingVaultParams) -> Result<()> {
+    edit_staking_vault::handler(ctx, params)
+}
+
+pub fn deposit_staking_vault(ctx: Context<DepositStakingVault>, amount: Amount) -> Result<()> {
+    deposit_staking_vault::handler(ctx, amount)
+}
+
+pub fn add_staking_yield(ctx: Context<AddStakingYield>, amount: Amount) -> Result<()> {
+    add_staking_yield::handler(ctx, amount)
+}
+
+pub fn withdraw_staking_vault(ctx: Context<WithdrawStakingVault>, shares: u64) -> Result<()> {
+    withdraw_staking_vault::handler(ctx, shares)
+}
+
+pub fn request_staking_withdrawal(ctx: Context<RequestStakingWithdrawal>, shares: u64) -> Result<()> {
+    request_staking_withdrawal::handler(ctx, shares)
+}
+
+pub fn claim_staking_withdrawal(ctx: Context<ClaimStakingWithdrawal>) -> Result<()> {
+    claim_staking_withdrawal::handler(ctx)
+}
+
+pub fn initialize_voting_escrow(ctx: Context<InitializeVotingEscrow>) -> Result<()> {
+    initialize_voting_escrow::handler(ctx)
+}
+
+pub fn checkpoint_voting_power(ctx: Context<CheckpointVotingPower>) -> Result<()> {
+    checkpoint_voting_power::handler(ctx)
+}
+
+pub fn get_voting_power(ctx: Context<GetVotingPower>) -> Result<u64> {
+    get_voting_power::handler(ctx)
+}
+
+pub fn set_lock_delegate(ctx: Context<SetLockDelegate>) -> Result<()> {
+    set_lock_delegate::handler(ctx)
+}
+
+pub fn create_vesting_grant(ctx: Context<CreateVestingGrant>, params: CreateVestingGrantParams) -> Result<()> {
+    create_vesting_grant::handler(ctx, params)
+}
+
+pub fn release_vesting_grant(ctx: Context<ReleaseVestingGrant>) -> Result<()> {
+    release_vesting_grant::handler(ctx)
+}
+
+pub fn revoke_vesting_grant(ctx: Context<RevokeVestingGrant>) -> Result<()> {
+    revoke_vesting_grant::handler(ctx)
+}
+
+pub fn resize_controller(ctx: Context<ResizeController>, max_products: u64) -> Result<()> {
+    resize_controller::handler(ctx, max_products)
+}
+
+pub fn migrate_controller(ctx: Context<MigrateController>) -> Result<()> {
+    migrate_controller::handler(ctx)
+}
+
+pub fn swap_assets<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapAssets<'info>>,
+    amount_in: Amount,
+    min_amount_out: Amount,
+    swap_data: Vec<u8>,
+) -> Result<()> {
+    manage_hedging_strategy::swap_assets(ctx, amount_in, min_amount_out, swap_data)
+}
+
+pub fn register_swap_program(ctx: Context<RegisterSwapProgram>, program_id: Pubkey) -> Result<()> {
+    register_swap_program::handler(ctx, program_id)
+}
+
+pub fn edit_swap_program(ctx: Context<EditSwapProgram>, is_enabled: bool) -> Result<()> {
+    edit_swap_program::handler(ctx, is_enabled)
+}
+
+pub fn initialize_hedging_strategy(ctx: Context<InitializeHedgingStrategy>, params: InitializeHedgingStrategyParams) -> Result<()> {
+    initialize_hedging_strategy::handler(ctx, params)
+}
+
+pub fn edit_hedging_strategy(ctx: Context<EditHedgingStrategy>, params: EditHedgingStrategyParams) -> Result<()> {
+    edit_hedging_strategy::handler(ctx, params)
+}
+
+pub fn rebalance_hedge(ctx: Context<RebalanceHedge>) -> Result<()> {
+    rebalance_hedge::handler(ctx)
+}
+
+pub fn mark_strategy(ctx: Context<MarkStrategy>) -> Result<()> {
+    mark_strategy::handler(ctx)
+}
+
+pub fn check_strategy_risk(ctx: Context<CheckStrategyRisk>) -> Result<()> {
+    check_strategy_risk::handler(ctx)
+}
+
+pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>, yield_share_bps: u16) -> Result<()> {
+    initialize_insurance_fund::handler(ctx, yield_share_bps)
+}
+
+pub fn edit_insurance_fund(ctx: Context<EditInsuranceFund>, yield_share_bps: u16) -> Result<()> {
+    edit_insurance_fund::handler(ctx, yield_share_bps)
+}
+
+pub fn top_up_insurance_fund(ctx: Context<TopUpInsuranceFund>, amount: Amount) -> Result<()> {
+    top_up_insurance_fund::handler(ctx, amount)
+}
+
+pub fn cover_strategy_loss(ctx: Context<CoverStrategyLoss>) -> Result<()> {
+    cover_strategy_loss::handler(ctx)
+}
+
+pub fn adjust_hedge_ratio(ctx: Context<AdjustHedgeRatio>) -> Result<()> {
+    adjust_hedge_ratio::handler(ctx)
+}
+
+pub fn initialize_hedging_audit_log(ctx: Context<InitializeHedgingAuditLog>) -> Result<()> {
+    initialize_hedging_audit_log::handler(ctx)
 }
\ No newline at end of file
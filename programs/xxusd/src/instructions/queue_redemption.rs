@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{Controller, KaminoDepository, QueuedRedemption, TermDepository, Amount};
+use crate::utils::calculate_term_redemption::calculate_redemption_split;
+use crate::utils::maths::checked_sub;
+use crate::CONTROLLER_NAMESPACE;
+
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const TERM_DEPOSITORY_SEED: &[u8] = b"term_depository";
+pub const QUEUED_REDEMPTION_SEED: &[u8] = b"queued_redemption";
+
+#[derive(Accounts)]
+#[instruction(redeemable_amount: Amount, nonce: u64)]
+pub struct QueueRedemption<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = redeemable_mint @XxusdError::InvalidRedeemableMint
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(mut)]
+    pub redeemable_mint: Box<Account<'info, Mint>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = redeemable_mint,
+        associated_token::authority = user,
+    )]
+    pub user_redeemable: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = user,
+    )]
+    pub user_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [TERM_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = term_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub term_depository: Box<Account<'info, TermDepository>>,
+
+    #[account(
+        init,
+        payer = user,
+        space = QueuedRedemption::LEN,
+        seeds = [
+            QUEUED_REDEMPTION_SEED,
+            term_depository.key().as_ref(),
+            user.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub queued_redemption: Box<Account<'info, QueuedRedemption>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> QueueRedemption<'info> {
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.redeemable_mint.to_account_info(),
+            from: self.user_redeemable.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.depository_collateral.to_account_info(),
+            to: self.user_collateral.to_account_info(),
+            authority: self.kamino_depository.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Fallback for `redeem` once the liquid depository runs dry: all the xxUSD is burnt now,
+/// the liquid depository pays out what it still can and the rest of the collateral is
+/// claimed from the term depository after it unlocks. `nonce` lets a user queue several
+/// redemptions against the same term depository.
+pub fn handler(ctx: Context<QueueRedemption>, redeemable_amount: Amount, nonce: u64) -> Result<()> {
+    require!(redeemable_amount.value() > 0, XxusdError::InvalidRedeemableAmount);
+    require!(
+        ctx.accounts.user_redeemable.amount >= redeemable_amount.value(),
+        XxusdError::InsufficientRedeemableBalance
+    );
+
+    let kamino_depository = &ctx.accounts.kamino_depository;
+    let liquid_redeemable_amount = if kamino_depository.is_enabled {
+        kamino_depository.redeemable_amount_under_management
+    } else {
+        0
+    };
+    let (liquid_amount, queued_amount) = calculate_redemption_split(
+        redeemable_amount.value(),
+        liquid_redeemable_amount,
+        ctx.accounts.depository_collateral.amount,
+    );
+    require!(queued_amount > 0, XxusdError::LiquidDepositoryNotExhausted);
+    require!(
+        ctx.accounts.term_depository.get_unqueued_redeemable_amount()? >= u128::from(queued_amount),
+        XxusdError::InsufficientTermDepositoryAmount
+    );
+
+    // 銷毀 xxUSD
+    token::burn(ctx.accounts.burn_context(), redeemable_amount.value())?;
+
+    // 流動存儲庫先支付能支付的部分，與 redeem 相同，假設 1:1 兌換
+    if liquid_amount > 0 {
+        let collateral_mint = ctx.accounts.collateral_mint.key();
+        let seeds = &[
+            KAMINO_DEPOSITORY_SEED,
+            collateral_mint.as_ref(),
+            &[ctx.accounts.kamino_depository.bump],
+        ];
+        let signer = &[&seeds[..]];
+        token::transfer(ctx.accounts.transfer_context().with_signer(signer), liquid_amount)?;
+
+        let kamino_depository = &mut ctx.accounts.kamino_depository;
+        kamino_depository.redeemable_amount_under_management = kamino_depository
+            .redeemable_amount_under_management
+            .checked_sub(u128::from(liquid_amount))
+            .ok_or(XxusdError::Overflow)?;
+    }
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let controller = &mut ctx.accounts.controller;
+    let current_supply = Amount::from_u128(controller.get_redeemable_circulating_supply())?;
+    let new_supply = checked_sub(current_supply, redeemable_amount)?;
+    controller.set_redeemable_circulating_supply(new_supply.to_u128())?;
+
+    let term_depository = &mut ctx.accounts.term_depository;
+    term_depository.queued_redeemable_amount = term_depository
+        .queued_redeemable_amount
+        .checked_add(u128::from(queued_amount))
+        .ok_or(XxusdError::Overflow)?;
+
+    let queued_redemption = &mut ctx.accounts.queued_redemption;
+    queued_redemption.bump = ctx.bumps.queued_redemption;
+    queued_redemption.user = ctx.accounts.user.key();
+    queued_redemption.term_depository = term_depository.key();
+    queued_redemption.nonce = nonce;
+    queued_redemption.redeemable_amount = queued_amount;
+    queued_redemption.collateral_amount = queued_amount;
+    queued_redemption.queued_time = current_time;
+
+    emit!(QueueRedemptionEvent {
+        user: queued_redemption.user,
+        term_depository: queued_redemption.term_depository,
+        nonce,
+        redeemable_amount,
+        liquid_amount: Amount::new(liquid_amount),
+        queued_amount: Amount::new(queued_amount),
+        unlock_time: term_depository.unlock_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct QueueRedemptionEvent {
+    pub user: Pubkey,
+    pub term_depository: Pubkey,
+    pub nonce: u64,
+    pub redeemable_amount: Amount,
+    /// Paid out by the liquid depository right away.
+    pub liquid_amount: Amount,
+    /// Claimable from the term depository once it unlocks.
+    pub queued_amount: Amount,
+    pub unlock_time: i64,
+}
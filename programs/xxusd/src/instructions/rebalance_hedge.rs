@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::adapters::perp::{CpiPerpAdapter, PerpAdapter, PerpMarginAccounts};
+use crate::error::XxusdError;
+use crate::instructions::cover_strategy_loss::cover_loss_from_insurance_fund;
+use crate::instructions::mark_strategy::emit_strategy_snapshot;
+use crate::instructions::top_up_insurance_fund::InsuranceFundDepositEvent;
+use crate::state::{controller::Controller, hedging_strategy::{HedgingStrategy, HedgingStrategyType}, insurance_fund::InsuranceFund, perp_hedge::PerpHedge, KaminoDepository};
+use crate::utils::calculate_hedge_size::{calculate_hedge_adjustment, calculate_usd_value};
+use crate::utils::calculate_strategy_pnl::calculate_bps_of;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const HEDGING_STRATEGY_SEED: &[u8] = b"hedging_strategy";
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const PERP_HEDGE_SEED: &[u8] = b"perp_hedge";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+
+#[derive(Accounts)]
+pub struct RebalanceHedge<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [HEDGING_STRATEGY_SEED, &[hedging_strategy.strategy_id]],
+        bump = hedging_strategy.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+        constraint = hedging_strategy.strategy_type == HedgingStrategyType::PerpShort @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub hedging_strategy: Box<Account<'info, HedgingStrategy>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    /// Only the mint's registered perp hedge may short against its collateral
+    #[account(
+        seeds = [PERP_HEDGE_SEED, collateral_mint.key().as_ref()],
+        bump = perp_hedge.bump,
+        constraint = perp_hedge.strategy_id == hedging_strategy.strategy_id @XxusdError::InvalidHedgingStrategy,
+    )]
+    pub perp_hedge: Box<Account<'info, PerpHedge>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = hedging_strategy,
+    )]
+    pub hedging_strategy_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED, collateral_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+        has_one = controller @XxusdError::InvalidController,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = insurance_fund,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: the strategy's perp venue
+    #[account(
+        executable,
+        address = hedging_strategy.venue_program @XxusdError::InvalidPerpAccount,
+    )]
+    pub perp_program: UncheckedAccount<'info>,
+
+    /// CHECK: owner and layout are checked by `CpiPerpAdapter`
+    #[account(address = hedging_strategy.venue_market @XxusdError::InvalidPerpAccount)]
+    pub perp_market: UncheckedAccount<'info>,
+
+    /// CHECK: owner, market and position owner are checked by `CpiPerpAdapter`
+    #[account(mut)]
+    pub perp_position: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the market by the perp venue
+    #[account(mut)]
+    pub perp_margin_vault: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+/// Resizes the strategy's short so it hedges the collateral held by the depository and the
+/// strategy, within the strategy's delta tolerance. `perp_hedge` makes it the only short
+/// sized against that collateral. A disabled or withdraw-only strategy
+/// unwinds its short.
+///
+/// Buying back part of the short settles its PnL in the strategy vault. The insurance fund
+/// takes its share of gains and the rest is returned to the depository. Losses reduce the
+/// strategy's principal and are covered by the insurance fund first.
+pub fn handler(ctx: Context<RebalanceHedge>) -> Result<()> {
+    let hedging_strategy_info = ctx.accounts.hedging_strategy.to_account_info();
+    let hedging_strategy_vault_info = ctx.accounts.hedging_strategy_vault.to_account_info();
+    let adapter = CpiPerpAdapter {
+        program: &ctx.accounts.perp_program.to_account_info(),
+        market: &ctx.accounts.perp_market.to_account_info(),
+        position: &ctx.accounts.perp_position.to_account_info(),
+        owner: &hedging_strategy_info,
+        margin: Some(PerpMarginAccounts {
+            margin_vault: &ctx.accounts.perp_margin_vault.to_account_info(),
+            owner_collateral: &hedging_strategy_vault_info,
+            token_program: &ctx.accounts.token_program.to_account_info(),
+        }),
+    };
+
+    let position = adapter.position()?;
+    require!(position.size <= 0, XxusdError::InvalidPerpAccount);
+    let short_size = position.size.unsigned_abs();
+
+    let collateral_amount = ctx.accounts.depository_collateral.amount
+        .checked_add(ctx.accounts.hedging_strategy_vault.amount)
+        .ok_or(XxusdError::MathOverflow)?;
+    let hedging_strategy = &ctx.accounts.hedging_strategy;
+    let hedge_ratio_bps = if hedging_strategy.can_increase_exposure() { hedging_strategy.hedge_ratio_bps } else { 0 };
+    let size_delta = calculate_hedge_adjustment(
+        collateral_amount,
+        short_size,
+        hedge_ratio_bps,
+        hedging_strategy.delta_tolerance_bps,
+    )?;
+
+    let seeds = &[
+        HEDGING_STRATEGY_SEED,
+        &[hedging_strategy.strategy_id],
+        &[hedging_strategy.bump],
+    ];
+    let signer = &[&seeds[..]];
+    if size_delta != 0 {
+        adapter.adjust_position(size_delta, signer)?;
+    }
+
+    // The PnL the venue settled in the vault when part of the short was bought back
+    let vault_amount = ctx.accounts.hedging_strategy_vault.amount;
+    ctx.accounts.hedging_strategy_vault.reload()?;
+    let realized_pnl = i64::try_from(i128::from(ctx.accounts.hedging_strategy_vault.amount) - i128::from(vault_amount))
+        .map_err(|_| XxusdError::MathOverflow)?;
+    if realized_pnl > 0 {
+        let insurance_amount = calculate_bps_of(realized_pnl.unsigned_abs(), ctx.accounts.insurance_fund.yield_share_bps)?;
+        if insurance_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: ctx.accounts.hedging_strategy_vault.to_account_info(),
+                to: ctx.accounts.insurance_fund_vault.to_account_info(),
+                authority: ctx.accounts.hedging_strategy.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer),
+                insurance_amount,
+            )?;
+            ctx.accounts.insurance_fund.record_deposit(insurance_amount)?;
+            ctx.accounts.insurance_fund_vault.reload()?;
+            emit!(InsuranceFundDepositEvent {
+                collateral_mint: ctx.accounts.collateral_mint.key(),
+                strategy_id: Some(ctx.accounts.hedging_strategy.strategy_id),
+                amount: insurance_amount,
+                balance: ctx.accounts.insurance_fund_vault.amount,
+            });
+        }
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.hedging_strategy_vault.to_account_info(),
+            to: ctx.accounts.depository_collateral.to_account_info(),
+            authority: ctx.accounts.hedging_strategy.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), cpi_accounts, signer),
+            realized_pnl.unsigned_abs() - insurance_amount,
+        )?;
+        ctx.accounts.hedging_strategy_vault.reload()?;
+    }
+
+    // Record the position as the venue reports it after the trade
+    let position = adapter.position()?;
+    require!(position.size <= 0, XxusdError::InvalidPerpAccount);
+    let mark_price = adapter.mark_price()?;
+    let decimals = ctx.accounts.collateral_mint.decimals;
+
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+    hedging_strategy.position_size = position.size.unsigned_abs();
+    hedging_strategy.entry_price = position.entry_price;
+    hedging_strategy.funding_paid = position.funding_paid;
+    hedging_strategy.mark_price = mark_price;
+    if realized_pnl < 0 {
+        hedging_strategy.deposited_amount = hedging_strategy.deposited_amount.saturating_sub(realized_pnl.unsigned_abs());
+    }
+    hedging_strategy.add_realized_pnl(realized_pnl)?;
+    if hedging_strategy.uncovered_loss > 0 {
+        let accounts = &mut *ctx.accounts;
+        cover_loss_from_insurance_fund(
+            &mut accounts.hedging_strategy,
+            &mut accounts.insurance_fund,
+            &mut accounts.insurance_fund_vault,
+            &accounts.depository_collateral,
+            &accounts.token_program,
+        )?;
+    }
+
+    let hedging_strategy = &mut ctx.accounts.hedging_strategy;
+    let vault_amount = ctx.accounts.hedging_strategy_vault.amount;
+    hedging_strategy.mark(vault_amount, Clock::get()?.unix_timestamp)?;
+    emit_strategy_snapshot(hedging_strategy, vault_amount);
+
+    emit!(RebalanceHedgeEvent {
+        strategy_id: hedging_strategy.strategy_id,
+        mark_price,
+        collateral_amount,
+        collateral_value: calculate_usd_value(collateral_amount, mark_price, decimals)?,
+        size_delta,
+        position_size: hedging_strategy.position_size,
+        position_value: calculate_usd_value(hedging_strategy.position_size, mark_price, decimals)?,
+        entry_price: hedging_strategy.entry_price,
+        funding_paid: hedging_strategy.funding_paid,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RebalanceHedgeEvent {
+    pub strategy_id: u8,
+    pub mark_price: u64,
+    pub collateral_amount: u64,
+    /// USD value of the collateral, 6 decimals.
+    pub collateral_value: u64,
+    /// Change applied to the perp position, negative when the short grew. Zero when the
+    /// hedge was already within tolerance.
+    pub size_delta: i64,
+    pub position_size: u64,
+    /// USD value of the short, 6 decimals.
+    pub position_value: u64,
+    pub entry_price: u64,
+    pub funding_paid: i64,
+}
@@ -5,7 +5,7 @@ use anchor_spl::token::{self, Token, TokenAccount, Mint, Burn, Transfer};
 use crate::error::XxusdError;
 use crate::state::{Controller, KaminoDepository, Amount};
 use crate::utils::maths::checked_sub;
-use crate::{CONTROLLER_NAMESPACE, JUPSOL_MINT_PUBKEY};
+use crate::CONTROLLER_NAMESPACE;
 
 pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
 
@@ -28,9 +28,6 @@ pub struct Redeem<'info> {
     #[account(mut)]
     pub redeemable_mint: Box<Account<'info, Mint>>,
 
-    #[account(
-        constraint = collateral_mint.key() == JUPSOL_MINT_PUBKEY @XxusdError::InvalidCollateralMint
-    )]
     pub collateral_mint: Box<Account<'info, Mint>>,
 
     #[account(
@@ -49,13 +46,22 @@ pub struct Redeem<'info> {
 
     #[account(
         mut,
-        seeds = [KAMINO_DEPOSITORY_SEED],
-        bump,
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
         has_one = controller @XxusdError::InvalidController,
         has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+        constraint = kamino_depository.is_enabled @XxusdError::CollateralMintDisabled,
+        constraint = kamino_depository.collateral_mint_decimals == collateral_mint.decimals @XxusdError::InvalidCollateralMintDecimals,
     )]
     pub kamino_depository: Box<Account<'info, KaminoDepository>>,
 
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -74,7 +80,7 @@ impl<'info> Redeem<'info> {
 
     fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
-            from: self.kamino_depository.to_account_info(),
+            from: self.depository_collateral.to_account_info(),
             to: self.user_collateral.to_account_info(),
             authority: self.kamino_depository.to_account_info(),
         };
@@ -95,9 +101,11 @@ pub fn handler(ctx: Context<Redeem>, redeemable_amount: Amount) -> Result<()> {
 
     // 從 Kamino 存儲庫轉移 jupSOL 到用戶
     let collateral_amount = redeemable_amount; // 假設 1:1 兌換，實際情況可能需要更複雜的計算
+    let collateral_mint = ctx.accounts.collateral_mint.key();
     let seeds = &[
-        KAMINO_DEPOSITORY_SEED.as_ref(),
-        &[ctx.bumps.kamino_depository],
+        KAMINO_DEPOSITORY_SEED,
+        collateral_mint.as_ref(),
+        &[ctx.accounts.kamino_depository.bump],
     ];
     let signer = &[&seeds[..]];
     token::transfer(
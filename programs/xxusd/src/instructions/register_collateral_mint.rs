@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{Controller, KaminoDepository};
+use crate::{BPS_POWER, CONTROLLER_NAMESPACE};
+
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterCollateralMintParams {
+    pub oracle: Pubkey,
+    pub redeemable_amount_under_management_cap: u128,
+    pub minting_fee_in_bps: u16,
+    pub redeeming_fee_in_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct RegisterCollateralMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = KaminoDepository::LEN,
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = kamino_depository,
+    )]
+    pub depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<RegisterCollateralMint>, params: RegisterCollateralMintParams) -> Result<()> {
+    require!(u64::from(params.minting_fee_in_bps) <= BPS_POWER, XxusdError::InvalidBps);
+    require!(u64::from(params.redeeming_fee_in_bps) <= BPS_POWER, XxusdError::InvalidBps);
+
+    let collateral_mint = &ctx.accounts.collateral_mint;
+    let kamino_depository = &mut ctx.accounts.kamino_depository;
+    kamino_depository.initialize(
+        ctx.bumps.kamino_depository,
+        ctx.accounts.controller.key(),
+        collateral_mint.key(),
+        collateral_mint.decimals,
+        params.oracle,
+        params.redeemable_amount_under_management_cap,
+        params.minting_fee_in_bps,
+        params.redeeming_fee_in_bps,
+    )?;
+
+    emit!(RegisterCollateralMintEvent {
+        depository: kamino_depository.key(),
+        collateral_mint: collateral_mint.key(),
+        collateral_mint_decimals: collateral_mint.decimals,
+        oracle: params.oracle,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RegisterCollateralMintEvent {
+    pub depository: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub collateral_mint_decimals: u8,
+    pub oracle: Pubkey,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{controller::Controller, swap_program::SwapProgram};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const SWAP_PROGRAM_SEED: &[u8] = b"swap_program";
+
+#[derive(Accounts)]
+#[instruction(program_id: Pubkey)]
+pub struct RegisterSwapProgram<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = SwapProgram::LEN,
+        seeds = [SWAP_PROGRAM_SEED, program_id.as_ref()],
+        bump,
+    )]
+    pub swap_program: Box<Account<'info, SwapProgram>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RegisterSwapProgram>, program_id: Pubkey) -> Result<()> {
+    let swap_program = &mut ctx.accounts.swap_program;
+    swap_program.initialize(ctx.bumps.swap_program, ctx.accounts.controller.key(), program_id)?;
+
+    emit!(SetSwapProgramEvent {
+        program_id,
+        is_enabled: true,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetSwapProgramEvent {
+    pub program_id: Pubkey,
+    pub is_enabled: bool,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{Controller, KaminoDepository, TermDepository};
+use crate::CONTROLLER_NAMESPACE;
+
+pub const KAMINO_DEPOSITORY_SEED: &[u8] = b"kamino_depository";
+pub const TERM_DEPOSITORY_SEED: &[u8] = b"term_depository";
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterTermDepositoryParams {
+    pub redeemable_amount_under_management_cap: u128,
+    pub unlock_time: i64,
+    pub is_liquid: bool,
+}
+
+#[derive(Accounts)]
+pub struct RegisterTermDepository<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_NAMESPACE],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    // Only collateral mints already registered with a liquid depository can be term deposited
+    #[account(
+        seeds = [KAMINO_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump = kamino_depository.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub kamino_depository: Box<Account<'info, KaminoDepository>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TermDepository::LEN,
+        seeds = [TERM_DEPOSITORY_SEED, collateral_mint.key().as_ref()],
+        bump,
+    )]
+    pub term_depository: Box<Account<'info, TermDepository>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = term_depository,
+    )]
+    pub term_depository_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<RegisterTermDepository>, params: RegisterTermDepositoryParams) -> Result<()> {
+    let term_depository = &mut ctx.accounts.term_depository;
+    term_depository.initialize(
+        ctx.bumps.term_depository,
+        ctx.accounts.controller.key(),
+        ctx.accounts.collateral_mint.key(),
+        params.redeemable_amount_under_management_cap,
+        params.unlock_time,
+        params.is_liquid,
+    )?;
+
+    emit!(RegisterTermDepositoryEvent {
+        depository: term_depository.key(),
+        collateral_mint: term_depository.collateral_mint,
+        unlock_time: params.unlock_time,
+        is_liquid: params.is_liquid,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RegisterTermDepositoryEvent {
+    pub depository: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub unlock_time: i64,
+    pub is_liquid: bool,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, vesting_grant::VestingGrant, Amount};
+use crate::utils::maths::{checked_add, checked_sub};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const VESTING_GRANT_SEED: &[u8] = b"vesting_grant";
+
+#[derive(Accounts)]
+pub struct ReleaseVestingGrant<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_GRANT_SEED, recipient.key().as_ref(), &vesting_grant.grant_id.to_le_bytes()],
+        bump = vesting_grant.bump,
+        has_one = lock_manager,
+        has_one = recipient @XxusdError::InvalidOwner,
+    )]
+    pub vesting_grant: Box<Account<'info, VestingGrant>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ReleaseVestingGrant<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.lock_vault.to_account_info(),
+            to: self.recipient_xxusd.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<ReleaseVestingGrant>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let vesting_grant = &ctx.accounts.vesting_grant;
+    let vested_amount = vesting_grant.get_vested_amount(current_time)?;
+    let releasable_amount = Amount::new(vested_amount.value().saturating_sub(vesting_grant.released_amount));
+    require!(releasable_amount.value() > 0, XxusdError::InsufficientReleasableAmount);
+
+    let seeds = &[
+        LOCK_MANAGER_SEED,
+        &[ctx.accounts.lock_manager.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), releasable_amount.value())?;
+
+    let vesting_grant = &mut ctx.accounts.vesting_grant;
+    let new_released_amount = checked_add(vesting_grant.get_released_amount(), releasable_amount)?;
+    vesting_grant.set_released_amount(new_released_amount);
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    let new_total_locked_amount = checked_sub(lock_manager.get_total_locked_amount(), releasable_amount)?;
+    lock_manager.set_total_locked_amount(new_total_locked_amount);
+
+    let controller = &mut ctx.accounts.controller;
+    let new_locked_supply = checked_sub(Amount::from_u128(controller.get_locked_xxusd_supply())?, releasable_amount)?;
+    controller.set_locked_xxusd_supply(new_locked_supply.to_u128())?;
+
+    emit!(ReleaseVestingGrantEvent {
+        vesting_grant: ctx.accounts.vesting_grant.key(),
+        recipient: ctx.accounts.recipient.key(),
+        amount: releasable_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReleaseVestingGrantEvent {
+    pub vesting_grant: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: Amount,
+}
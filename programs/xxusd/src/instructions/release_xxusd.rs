@@ -1,14 +1,16 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
 use anchor_spl::associated_token::AssociatedToken;
 
-use crate::state::{controller::Controller, lock_manager::LockManager, Amount, Timestamp};
-use crate::utils::maths::{checked_sub, checked_sub_timestamp};
+use crate::state::{controller::Controller, lock_manager::LockManager, lock_position::LockPosition, voting_escrow::VotingEscrow, Amount, Timestamp};
+use crate::utils::maths::{checked_add, checked_sub};
+use crate::utils::calculate_vested_amount::calculate_releasable_amount as calculate_schedule_releasable_amount;
 use crate::error::XxusdError;
-use core::cmp; // 確保已導入
 
 pub const CONTROLLER_SEED: &[u8] = b"controller";
 pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+pub const VOTING_ESCROW_SEED: &[u8] = b"voting_escrow";
 
 #[derive(Accounts)]
 pub struct ReleaseXxusd<'info> {
@@ -30,10 +32,42 @@ pub struct ReleaseXxusd<'info> {
     )]
     pub lock_manager: Box<Account<'info, LockManager>>,
 
+    #[account(
+        mut,
+        seeds = [VOTING_ESCROW_SEED],
+        bump = voting_escrow.bump,
+        has_one = lock_manager,
+    )]
+    pub voting_escrow: Box<Account<'info, VotingEscrow>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+        has_one = lock_manager,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    #[account(
+        mut,
+        address = lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+
+    /// The position's receipt, held by the signer or by the beneficiary the signer is the delegate of
+    #[account(
+        mut,
+        constraint = user_receipt.mint == lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+        constraint = user_receipt.amount == 1 @XxusdError::InvalidLockReceipt,
+        constraint = is_receipt_holder_or_delegate(&user_receipt, &lock_position, &user.key()) @XxusdError::InvalidLockReceipt,
+    )]
+    pub user_receipt: Box<Account<'info, TokenAccount>>,
+
+    /// The receipt holder's xxUSD account, releases never go to a delegate
     #[account(
         mut,
         associated_token::mint = controller.xxusd_mint,
-        associated_token::authority = user,
+        associated_token::authority = user_receipt.owner,
     )]
     pub user_xxusd: Box<Account<'info, TokenAccount>>,
 
@@ -44,11 +78,23 @@ pub struct ReleaseXxusd<'info> {
     )]
     pub lock_vault: Box<Account<'info, TokenAccount>>,
 
+    /// Pays out the remaining rewards once the position is fully released
+    #[account(
+        mut,
+        address = lock_manager.lock_reward_vault @XxusdError::InvalidLockRewardVault,
+    )]
+    pub lock_reward_vault: Box<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
 
+/// The holder of the receipt, or the delegate recorded on the position through `set_lock_delegate`
+fn is_receipt_holder_or_delegate(receipt: &TokenAccount, lock_position: &LockPosition, signer: &Pubkey) -> bool {
+    receipt.owner == *signer || lock_position.delegate == Some(*signer)
+}
+
 impl<'info> ReleaseXxusd<'info> {
     fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_accounts = Transfer {
@@ -58,6 +104,24 @@ impl<'info> ReleaseXxusd<'info> {
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
+
+    fn reward_transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.lock_reward_vault.to_account_info(),
+            to: self.user_xxusd.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn burn_receipt_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.receipt_mint.to_account_info(),
+            from: self.user_receipt.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
 }
 
 pub fn handler(ctx: Context<ReleaseXxusd>) -> Result<()> {
@@ -68,13 +132,25 @@ pub fn handler(ctx: Context<ReleaseXxusd>) -> Result<()> {
         perform_immutable_operations(&ctx.accounts, current_time)?;
 
     // 執行可變操作
-    update_lock_manager(&mut ctx.accounts.lock_manager, current_time, releasable_amount, current_total_locked_amount)?;
+    let acc_reward_per_share = ctx.accounts.lock_manager.acc_reward_per_share;
+    ctx.accounts.lock_position.settle_rewards(acc_reward_per_share)?;
+    update_lock_position(&mut ctx.accounts.lock_position, releasable_amount)?;
+    update_lock_manager(&mut ctx.accounts.lock_manager, releasable_amount, current_total_locked_amount)?;
+    ctx.accounts.lock_manager.update_position_weight(&mut ctx.accounts.lock_position)?;
+    ctx.accounts.voting_escrow.update_position_voting_power(&mut ctx.accounts.lock_position, current_time.value())?;
     perform_token_transfer(&ctx, releasable_amount)?;
     update_controller(&mut ctx.accounts.controller, current_locked_supply, releasable_amount)?;
 
+    // 全部釋放後發放剩餘獎勵並銷毀收據
+    if ctx.accounts.lock_position.is_fully_released() {
+        finalize_lock_position(ctx.accounts, ctx.bumps.lock_manager)?;
+    }
+
     // 發出釋放事件
     emit!(ReleaseEvent {
         user: ctx.accounts.user.key(),
+        beneficiary: ctx.accounts.user_receipt.owner,
+        lock_position: ctx.accounts.lock_position.key(),
         amount: releasable_amount,
     });
 
@@ -82,32 +158,31 @@ pub fn handler(ctx: Context<ReleaseXxusd>) -> Result<()> {
 }
 
 fn perform_immutable_operations(accounts: &ReleaseXxusd, current_time: Timestamp) -> Result<(Amount, Amount, u128)> {
-    let lock_period = accounts.lock_manager.locks[0].lock_period.value() as u64;
+    let lock_period = accounts.lock_position.lock_period as u64;
     require!(lock_period > 0, XxusdError::InvalidLockPeriod);
 
     let current_total_locked_amount = accounts.lock_manager.get_total_locked_amount();
     let current_locked_supply = accounts.controller.get_locked_xxusd_supply();
 
     // 計算可釋放金額
-    let (releasable_amount, _) = calculate_releasable_amount(&accounts.lock_manager, current_time)?;
+    let releasable_amount = calculate_releasable_amount(&accounts.lock_position, current_time)?;
 
     Ok((releasable_amount, current_total_locked_amount, current_locked_supply))
 }
 
+fn update_lock_position(lock_position: &mut LockPosition, releasable_amount: Amount) -> Result<()> {
+    // 更新鎖定狀態
+    let new_released_amount = checked_add(lock_position.get_released_amount(), releasable_amount)?;
+    lock_position.set_released_amount(new_released_amount);
+
+    Ok(())
+}
+
 fn update_lock_manager(
     lock_manager: &mut LockManager,
-    current_time: Timestamp,
     releasable_amount: Amount,
     current_total_locked_amount: Amount,
 ) -> Result<()> {
-    let user_lock = lock_manager.locks.iter_mut()
-        .find(|lock| lock.amount.value() > 0)
-        .ok_or(XxusdError::LockNotFound)?;
-
-    // 更新鎖定狀態
-    user_lock.amount = checked_sub(user_lock.amount, releasable_amount)?;
-    user_lock.lock_time = current_time;
-
     // 更新鎖定管理器狀態
     let new_total_locked_amount = checked_sub(current_total_locked_amount, releasable_amount)?;
     lock_manager.set_total_locked_amount(new_total_locked_amount); // 移除了 ? 運算符
@@ -132,6 +207,26 @@ fn perform_token_transfer(
     Ok(())
 }
 
+fn finalize_lock_position(accounts: &mut ReleaseXxusd, lock_manager_bump: u8) -> Result<()> {
+    let reward_amount = accounts.lock_position.pending_rewards;
+    if reward_amount > 0 {
+        let seeds = &[
+            LOCK_MANAGER_SEED,
+            &[lock_manager_bump],
+        ];
+        let signer = &[&seeds[..]];
+        token::transfer(accounts.reward_transfer_context().with_signer(signer), reward_amount)?;
+        accounts.lock_position.pending_rewards = 0;
+    }
+
+    // Only the holder can burn the receipt, a delegate leaves it with the holder
+    if accounts.user_receipt.owner == accounts.user.key() {
+        token::burn(accounts.burn_receipt_context(), 1)?;
+    }
+
+    Ok(())
+}
+
 fn update_controller(
     controller: &mut Controller,
     current_locked_supply: u128,
@@ -143,27 +238,22 @@ fn update_controller(
     Ok(())
 }
 
-fn calculate_releasable_amount(lock_manager: &LockManager, current_time: Timestamp) -> Result<(Amount, u64)> {
-    let user_lock = lock_manager.locks.iter()
-        .find(|lock| lock.amount.value() > 0)
-        .ok_or(XxusdError::LockNotFound)?;
-
-    let days_passed = checked_sub_timestamp(current_time, user_lock.lock_time)?;
-    let days_passed_u64 = (days_passed.abs() as u64) / 86400; // 86400 秒為一天
-    let lock_period = user_lock.lock_period.value() as u64;
-
-    let releasable_amount = Amount::from_u128(cmp::min(
-        user_lock.amount.to_u128(),
-        days_passed_u64 as u128 * user_lock.amount.to_u128() / lock_period as u128
-    )).map_err(|_| XxusdError::Overflow)?;
+fn calculate_releasable_amount(lock_position: &LockPosition, current_time: Timestamp) -> Result<Amount> {
+    let releasable_amount = Amount::new(calculate_schedule_releasable_amount(
+        &lock_position.get_vesting_schedule(),
+        lock_position.released_amount,
+        current_time.value(),
+    )?);
 
     require!(releasable_amount.value() > 0, XxusdError::InsufficientReleasableAmount);
 
-    Ok((releasable_amount, days_passed_u64))
+    Ok(releasable_amount)
 }
 
 #[event]
 pub struct ReleaseEvent {
     pub user: Pubkey,
+    pub beneficiary: Pubkey,
+    pub lock_position: Pubkey,
     pub amount: Amount,
 }
\ No newline at end of file
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault, staking_withdrawal_request::StakingWithdrawalRequest, Amount};
+use crate::utils::calculate_staking_shares::convert_to_assets;
+use crate::utils::maths::{checked_add, checked_sub};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+pub const STAKING_WITHDRAWAL_REQUEST_SEED: &[u8] = b"staking_withdrawal_request";
+
+#[derive(Accounts)]
+pub struct RequestStakingWithdrawal<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+        has_one = share_mint @XxusdError::InvalidMint,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+    )]
+    pub user_sxxusd: Box<Account<'info, TokenAccount>>,
+
+    /// One outstanding request per user, closed by `claim_staking_withdrawal`
+    #[account(
+        init,
+        payer = user,
+        space = StakingWithdrawalRequest::LEN,
+        seeds = [STAKING_WITHDRAWAL_REQUEST_SEED, staking_vault.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub withdrawal_request: Box<Account<'info, StakingWithdrawalRequest>>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> RequestStakingWithdrawal<'info> {
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.share_mint.to_account_info(),
+            from: self.user_sxxusd.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Burns sxxUSD at the current share price and sets the xxUSD aside until the cooldown ends.
+/// Yield added during the cooldown goes to the remaining holders.
+pub fn handler(ctx: Context<RequestStakingWithdrawal>, shares: u64) -> Result<()> {
+    let staking_vault = &ctx.accounts.staking_vault;
+    require!(staking_vault.cooldown_period > 0, XxusdError::StakingCooldownDisabled);
+    require!(shares > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.user_sxxusd.amount >= shares, XxusdError::InsufficientBalance);
+
+    let amount = Amount::new(convert_to_assets(shares, staking_vault.total_assets, ctx.accounts.share_mint.supply)?);
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    let claimable_time = Clock::get()?
+        .unix_timestamp
+        .checked_add(staking_vault.cooldown_period)
+        .ok_or(XxusdError::MathOverflow)?;
+
+    token::burn(ctx.accounts.burn_context(), shares)?;
+
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    let new_total_assets = checked_sub(staking_vault.get_total_assets(), amount)?;
+    staking_vault.set_total_assets(new_total_assets);
+    let new_pending_withdrawal_amount = checked_add(staking_vault.get_pending_withdrawal_amount(), amount)?;
+    staking_vault.set_pending_withdrawal_amount(new_pending_withdrawal_amount);
+
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    withdrawal_request.initialize(
+        ctx.bumps.withdrawal_request,
+        ctx.accounts.user.key(),
+        staking_vault.key(),
+        amount.value(),
+        claimable_time,
+    )?;
+
+    emit!(RequestStakingWithdrawalEvent {
+        user: ctx.accounts.user.key(),
+        shares,
+        amount,
+        claimable_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RequestStakingWithdrawalEvent {
+    pub user: Pubkey,
+    pub shares: u64,
+    pub amount: Amount,
+    pub claimable_time: i64,
+}
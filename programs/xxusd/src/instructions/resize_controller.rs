@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+use crate::state::Controller;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+/// Keeps the controller within the 10KiB a single realloc may grow an account by.
+pub const MAX_PRODUCTS_LIMIT: u64 = 512;
+
+#[derive(Accounts)]
+#[instruction(max_products: u64)]
+pub struct ResizeController<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+        realloc = Controller::space(max_products),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grows or shrinks the controller to hold `max_products` product prices.
+pub fn handler(ctx: Context<ResizeController>, max_products: u64) -> Result<()> {
+    let controller = &mut ctx.accounts.controller;
+    require!(max_products <= MAX_PRODUCTS_LIMIT, XxusdError::InvalidMaxProducts);
+    require!(
+        max_products >= controller.product_prices.len() as u64,
+        XxusdError::InvalidMaxProducts
+    );
+
+    let old_max_products = controller.max_products;
+    controller.max_products = max_products;
+
+    emit!(ResizeControllerEvent {
+        controller: controller.key(),
+        old_max_products,
+        max_products,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResizeControllerEvent {
+    pub controller: Pubkey,
+    pub old_max_products: u64,
+    pub max_products: u64,
+}
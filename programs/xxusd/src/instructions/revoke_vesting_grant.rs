@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, lock_manager::LockManager, vesting_grant::VestingGrant, Amount};
+use crate::utils::maths::checked_sub;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const LOCK_MANAGER_SEED: &[u8] = b"lock_manager";
+pub const VESTING_GRANT_SEED: &[u8] = b"vesting_grant";
+
+#[derive(Accounts)]
+pub struct RevokeVestingGrant<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_MANAGER_SEED],
+        bump = lock_manager.bump,
+        has_one = controller,
+    )]
+    pub lock_manager: Box<Account<'info, LockManager>>,
+
+    #[account(
+        mut,
+        seeds = [VESTING_GRANT_SEED, vesting_grant.recipient.as_ref(), &vesting_grant.grant_id.to_le_bytes()],
+        bump = vesting_grant.bump,
+        has_one = lock_manager,
+        constraint = vesting_grant.revocable @XxusdError::VestingGrantNotRevocable,
+        constraint = !vesting_grant.is_revoked() @XxusdError::VestingGrantRevoked,
+    )]
+    pub vesting_grant: Box<Account<'info, VestingGrant>>,
+
+    /// Receives the unvested part of the grant
+    #[account(
+        mut,
+        address = lock_manager.treasury @XxusdError::InvalidTreasury,
+    )]
+    pub treasury: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = lock_manager,
+    )]
+    pub lock_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> RevokeVestingGrant<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.lock_vault.to_account_info(),
+            to: self.treasury.to_account_info(),
+            authority: self.lock_manager.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Stops vesting now and returns the unvested part to the treasury. What already
+/// vested stays releasable by the recipient.
+pub fn handler(ctx: Context<RevokeVestingGrant>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let vesting_grant = &ctx.accounts.vesting_grant;
+    let vested_amount = vesting_grant.get_vested_amount(current_time)?;
+    let unvested_amount = checked_sub(vesting_grant.get_total_amount(), vested_amount)?;
+
+    if unvested_amount.value() > 0 {
+        let seeds = &[
+            LOCK_MANAGER_SEED,
+            &[ctx.accounts.lock_manager.bump],
+        ];
+        let signer = &[&seeds[..]];
+        token::transfer(ctx.accounts.transfer_context().with_signer(signer), unvested_amount.value())?;
+    }
+
+    let vesting_grant = &mut ctx.accounts.vesting_grant;
+    vesting_grant.revoked_time = current_time;
+
+    let lock_manager = &mut ctx.accounts.lock_manager;
+    let new_total_locked_amount = checked_sub(lock_manager.get_total_locked_amount(), unvested_amount)?;
+    lock_manager.set_total_locked_amount(new_total_locked_amount);
+
+    let controller = &mut ctx.accounts.controller;
+    let new_locked_supply = checked_sub(Amount::from_u128(controller.get_locked_xxusd_supply())?, unvested_amount)?;
+    controller.set_locked_xxusd_supply(new_locked_supply.to_u128())?;
+
+    emit!(RevokeVestingGrantEvent {
+        vesting_grant: ctx.accounts.vesting_grant.key(),
+        recipient: ctx.accounts.vesting_grant.recipient,
+        vested_amount,
+        returned_amount: unvested_amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevokeVestingGrantEvent {
+    pub vesting_grant: Pubkey,
+    pub recipient: Pubkey,
+    pub vested_amount: Amount,
+    pub returned_amount: Amount,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::XxusdError;
+use crate::state::lock_position::LockPosition;
+
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+
+#[derive(Accounts)]
+pub struct SetLockDelegate<'info> {
+    pub beneficiary: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOCK_POSITION_SEED, lock_position.owner.as_ref(), &lock_position.nonce.to_le_bytes()],
+        bump = lock_position.bump,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    /// The position's receipt, held by the beneficiary
+    #[account(
+        constraint = beneficiary_receipt.mint == lock_position.receipt_mint @XxusdError::InvalidLockReceipt,
+        constraint = beneficiary_receipt.owner == beneficiary.key() @XxusdError::InvalidLockReceipt,
+        constraint = beneficiary_receipt.amount == 1 @XxusdError::InvalidLockReceipt,
+    )]
+    pub beneficiary_receipt: Box<Account<'info, TokenAccount>>,
+
+    /// The new delegate, or none to remove the current one
+    /// CHECK: only recorded on the lock position
+    pub delegate: Option<UncheckedAccount<'info>>,
+}
+
+/// Lets a delegate release vested xxUSD to the beneficiary. The delegate is recorded on the
+/// position rather than approved on the receipt, so it can never move the receipt itself.
+pub fn handler(ctx: Context<SetLockDelegate>) -> Result<()> {
+    let delegate = ctx.accounts.delegate.as_ref().map(|delegate| delegate.key());
+    ctx.accounts.lock_position.delegate = delegate;
+
+    emit!(SetLockDelegateEvent {
+        lock_position: ctx.accounts.lock_position.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        delegate,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SetLockDelegateEvent {
+    pub lock_position: Pubkey,
+    pub beneficiary: Pubkey,
+    pub delegate: Option<Pubkey>,
+}
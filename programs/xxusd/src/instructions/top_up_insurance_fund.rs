@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, insurance_fund::InsuranceFund, Amount};
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+
+#[derive(Accounts)]
+pub struct TopUpInsuranceFund<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+        has_one = authority,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    pub collateral_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_FUND_SEED, collateral_mint.key().as_ref()],
+        bump = insurance_fund.bump,
+        has_one = controller @XxusdError::InvalidController,
+        has_one = collateral_mint @XxusdError::InvalidCollateralMint,
+    )]
+    pub insurance_fund: Box<Account<'info, InsuranceFund>>,
+
+    #[account(
+        mut,
+        associated_token::mint = collateral_mint,
+        associated_token::authority = insurance_fund,
+    )]
+    pub insurance_fund_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = authority_collateral.owner == authority.key() @XxusdError::InvalidOwner,
+        constraint = authority_collateral.mint == collateral_mint.key() @XxusdError::InvalidMint,
+    )]
+    pub authority_collateral: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> TopUpInsuranceFund<'info> {
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.authority_collateral.to_account_info(),
+            to: self.insurance_fund_vault.to_account_info(),
+            authority: self.authority.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+pub fn handler(ctx: Context<TopUpInsuranceFund>, amount: Amount) -> Result<()> {
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.authority_collateral.amount >= amount.value(), XxusdError::InsufficientFunds);
+
+    token::transfer(ctx.accounts.transfer_context(), amount.value())?;
+    ctx.accounts.insurance_fund.record_deposit(amount.value())?;
+    ctx.accounts.insurance_fund_vault.reload()?;
+
+    emit!(InsuranceFundDepositEvent {
+        collateral_mint: ctx.accounts.collateral_mint.key(),
+        strategy_id: None,
+        amount: amount.value(),
+        balance: ctx.accounts.insurance_fund_vault.amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct InsuranceFundDepositEvent {
+    pub collateral_mint: Pubkey,
+    /// The strategy whose yield was shared, `None` for admin top-ups.
+    pub strategy_id: Option<u8>,
+    pub amount: u64,
+    pub balance: u64,
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::XxusdError;
+use crate::state::{controller::Controller, staking_vault::StakingVault, Amount};
+use crate::utils::calculate_staking_shares::convert_to_assets;
+use crate::utils::maths::checked_sub;
+
+pub const CONTROLLER_SEED: &[u8] = b"controller";
+pub const STAKING_VAULT_SEED: &[u8] = b"staking_vault";
+
+#[derive(Accounts)]
+pub struct WithdrawStakingVault<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONTROLLER_SEED],
+        bump,
+    )]
+    pub controller: Box<Account<'info, Controller>>,
+
+    #[account(
+        mut,
+        seeds = [STAKING_VAULT_SEED],
+        bump = staking_vault.bump,
+        has_one = controller,
+        has_one = share_mint @XxusdError::InvalidMint,
+        has_one = vault,
+    )]
+    pub staking_vault: Box<Account<'info, StakingVault>>,
+
+    #[account(mut)]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = controller.xxusd_mint,
+        associated_token::authority = user,
+    )]
+    pub user_xxusd: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = user,
+    )]
+    pub user_sxxusd: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawStakingVault<'info> {
+    fn burn_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
+        let cpi_accounts = Burn {
+            mint: self.share_mint.to_account_info(),
+            from: self.user_sxxusd.to_account_info(),
+            authority: self.user.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user_xxusd.to_account_info(),
+            authority: self.staking_vault.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+/// Burns sxxUSD for its share of the vault. Only available while the cooldown is disabled.
+pub fn handler(ctx: Context<WithdrawStakingVault>, shares: u64) -> Result<()> {
+    let staking_vault = &ctx.accounts.staking_vault;
+    require!(staking_vault.cooldown_period == 0, XxusdError::StakingCooldownRequired);
+    require!(shares > 0, XxusdError::InvalidAmount);
+    require!(ctx.accounts.user_sxxusd.amount >= shares, XxusdError::InsufficientBalance);
+
+    let amount = Amount::new(convert_to_assets(shares, staking_vault.total_assets, ctx.accounts.share_mint.supply)?);
+    require!(amount.value() > 0, XxusdError::InvalidAmount);
+
+    token::burn(ctx.accounts.burn_context(), shares)?;
+
+    let seeds = &[
+        STAKING_VAULT_SEED,
+        &[staking_vault.bump],
+    ];
+    let signer = &[&seeds[..]];
+    token::transfer(ctx.accounts.transfer_context().with_signer(signer), amount.value())?;
+
+    let staking_vault = &mut ctx.accounts.staking_vault;
+    let new_total_assets = checked_sub(staking_vault.get_total_assets(), amount)?;
+    staking_vault.set_total_assets(new_total_assets);
+
+    emit!(WithdrawStakingVaultEvent {
+        user: ctx.accounts.user.key(),
+        shares,
+        amount,
+        total_assets: new_total_assets,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawStakingVaultEvent {
+    pub user: Pubkey,
+    pub shares: u64,
+    pub amount: Amount,
+    pub total_assets: Amount,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+pub mod adapters;
+pub mod oracle;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -10,11 +12,11 @@ use instructions::*;
 use state::*;
 use error::XxusdError;
 
-use state::{Amount, Timestamp};
+use state::Amount;
 
 // 定義常量
 pub const CONTROLLER_NAMESPACE: &[u8] = b"controller";
-pub const JUPSOL_MINT_PUBKEY: Pubkey = Pubkey::new_from_array([0; 32]); // 替換為實際的 JUPSOL_MINT_PUBKEY
+pub const BPS_POWER: u64 = 10_000;
 
 declare_id!("Cpsquy1RbEb4N3FXDKBzrWMKTLLvBp1BBSvp899EHhCb");
 
@@ -26,8 +28,8 @@ pub mod xxusd {
         initialize_controller_handler(ctx, params)
     }
 
-    pub fn lock_xxusd(ctx: Context<LockXxusd>, amount: Amount, lock_period: Timestamp) -> anchor_lang::Result<()> {
-        lock_xxusd_handler(ctx, amount, lock_period)
+    pub fn lock_xxusd(ctx: Context<LockXxusd>, params: LockXxusdParams) -> anchor_lang::Result<()> {
+        lock_xxusd_handler(ctx, params)
     }
 
     pub fn release_xxusd(ctx: Context<ReleaseXxusd>) -> anchor_lang::Result<()> {
@@ -49,4 +51,193 @@ pub mod xxusd {
     pub fn manage_hedging_strategy(ctx: Context<ManageHedgingStrategy>, amount: Amount, is_deposit: bool) -> anchor_lang::Result<()> {
         manage_hedging_strategy_handler(ctx, amount, is_deposit)
     }
+
+    pub fn register_collateral_mint(ctx: Context<RegisterCollateralMint>, params: RegisterCollateralMintParams) -> anchor_lang::Result<()> {
+        register_collateral_mint_handler(ctx, params)
+    }
+
+    pub fn edit_collateral_mint(ctx: Context<EditCollateralMint>, params: EditCollateralMintParams) -> anchor_lang::Result<()> {
+        edit_collateral_mint_handler(ctx, params)
+    }
+
+    pub fn register_term_depository(ctx: Context<RegisterTermDepository>, params: RegisterTermDepositoryParams) -> anchor_lang::Result<()> {
+        register_term_depository_handler(ctx, params)
+    }
+
+    pub fn edit_term_depository(ctx: Context<EditTermDepository>, params: EditTermDepositoryParams) -> anchor_lang::Result<()> {
+        edit_term_depository_handler(ctx, params)
+    }
+
+    pub fn allocate_term_deposit(ctx: Context<AllocateTermDeposit>, amount: Amount) -> anchor_lang::Result<()> {
+        allocate_term_deposit_handler(ctx, amount)
+    }
+
+    pub fn queue_redemption(ctx: Context<QueueRedemption>, redeemable_amount: Amount, nonce: u64) -> anchor_lang::Result<()> {
+        queue_redemption_handler(ctx, redeemable_amount, nonce)
+    }
+
+    pub fn claim_queued_redemption(ctx: Context<ClaimQueuedRedemption>) -> anchor_lang::Result<()> {
+        claim_queued_redemption_handler(ctx)
+    }
+
+    pub fn initialize_lock_manager(ctx: Context<InitializeLockManager>) -> anchor_lang::Result<()> {
+        initialize_lock_manager_handler(ctx)
+    }
+
+    pub fn initialize_lock_tier(ctx: Context<InitializeLockTier>, params: InitializeLockTierParams) -> anchor_lang::Result<()> {
+        initialize_lock_tier_handler(ctx, params)
+    }
+
+    pub fn edit_lock_tier(ctx: Context<EditLockTier>, params: EditLockTierParams) -> anchor_lang::Result<()> {
+        edit_lock_tier_handler(ctx, params)
+    }
+
+    pub fn edit_lock_manager(ctx: Context<EditLockManager>, params: EditLockManagerParams) -> anchor_lang::Result<()> {
+        edit_lock_manager_handler(ctx, params)
+    }
+
+    pub fn early_release(ctx: Context<EarlyRelease>) -> anchor_lang::Result<()> {
+        early_release_handler(ctx)
+    }
+
+    pub fn increase_lock_amount(ctx: Context<IncreaseLockAmount>, amount: Amount) -> anchor_lang::Result<()> {
+        increase_lock_amount_handler(ctx, amount)
+    }
+
+    pub fn extend_lock_period(ctx: Context<ExtendLockPeriod>, tier_id: u8) -> anchor_lang::Result<()> {
+        extend_lock_period_handler(ctx, tier_id)
+    }
+
+    pub fn deposit_lock_rewards(ctx: Context<DepositLockRewards>, amount: Amount) -> anchor_lang::Result<()> {
+        deposit_lock_rewards_handler(ctx, amount)
+    }
+
+    pub fn claim_lock_rewards(ctx: Context<ClaimLockRewards>) -> anchor_lang::Result<()> {
+        claim_lock_rewards_handler(ctx)
+    }
+
+    pub fn initialize_staking_vault(ctx: Context<InitializeStakingVault>, cooldown_period: i64) -> anchor_lang::Result<()> {
+        initialize_staking_vault_handler(ctx, cooldown_period)
+    }
+
+    pub fn edit_staking_vault(ctx: Context<EditStakingVault>, params: EditStakingVaultParams) -> anchor_lang::Result<()> {
+        edit_staking_vault_handler(ctx, params)
+    }
+
+    pub fn deposit_staking_vault(ctx: Context<DepositStakingVault>, amount: Amount) -> anchor_lang::Result<()> {
+        deposit_staking_vault_handler(ctx, amount)
+    }
+
+    pub fn add_staking_yield(ctx: Context<AddStakingYield>, amount: Amount) -> anchor_lang::Result<()> {
+        add_staking_yield_handler(ctx, amount)
+    }
+
+    pub fn withdraw_staking_vault(ctx: Context<WithdrawStakingVault>, shares: u64) -> anchor_lang::Result<()> {
+        withdraw_staking_vault_handler(ctx, shares)
+    }
+
+    pub fn request_staking_withdrawal(ctx: Context<RequestStakingWithdrawal>, shares: u64) -> anchor_lang::Result<()> {
+        request_staking_withdrawal_handler(ctx, shares)
+    }
+
+    pub fn claim_staking_withdrawal(ctx: Context<ClaimStakingWithdrawal>) -> anchor_lang::Result<()> {
+        claim_staking_withdrawal_handler(ctx)
+    }
+
+    pub fn initialize_voting_escrow(ctx: Context<InitializeVotingEscrow>) -> anchor_lang::Result<()> {
+        initialize_voting_escrow_handler(ctx)
+    }
+
+    pub fn checkpoint_voting_power(ctx: Context<CheckpointVotingPower>) -> anchor_lang::Result<()> {
+        checkpoint_voting_power_handler(ctx)
+    }
+
+    pub fn get_voting_power(ctx: Context<GetVotingPower>) -> anchor_lang::Result<u64> {
+        get_voting_power_handler(ctx)
+    }
+
+    pub fn set_lock_delegate(ctx: Context<SetLockDelegate>) -> anchor_lang::Result<()> {
+        set_lock_delegate_handler(ctx)
+    }
+
+    pub fn create_vesting_grant(ctx: Context<CreateVestingGrant>, params: CreateVestingGrantParams) -> anchor_lang::Result<()> {
+        create_vesting_grant_handler(ctx, params)
+    }
+
+    pub fn release_vesting_grant(ctx: Context<ReleaseVestingGrant>) -> anchor_lang::Result<()> {
+        release_vesting_grant_handler(ctx)
+    }
+
+    pub fn revoke_vesting_grant(ctx: Context<RevokeVestingGrant>) -> anchor_lang::Result<()> {
+        revoke_vesting_grant_handler(ctx)
+    }
+
+    pub fn resize_controller(ctx: Context<ResizeController>, max_products: u64) -> anchor_lang::Result<()> {
+        resize_controller_handler(ctx, max_products)
+    }
+
+    pub fn migrate_controller(ctx: Context<MigrateController>) -> anchor_lang::Result<()> {
+        migrate_controller_handler(ctx)
+    }
+
+    pub fn swap_assets<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapAssets<'info>>,
+        amount_in: Amount,
+        min_amount_out: Amount,
+        swap_data: Vec<u8>,
+    ) -> anchor_lang::Result<()> {
+        swap_assets_handler(ctx, amount_in, min_amount_out, swap_data)
+    }
+
+    pub fn register_swap_program(ctx: Context<RegisterSwapProgram>, program_id: Pubkey) -> anchor_lang::Result<()> {
+        register_swap_program_handler(ctx, program_id)
+    }
+
+    pub fn edit_swap_program(ctx: Context<EditSwapProgram>, is_enabled: bool) -> anchor_lang::Result<()> {
+        edit_swap_program_handler(ctx, is_enabled)
+    }
+
+    pub fn initialize_hedging_strategy(ctx: Context<InitializeHedgingStrategy>, params: InitializeHedgingStrategyParams) -> anchor_lang::Result<()> {
+        initialize_hedging_strategy_handler(ctx, params)
+    }
+
+    pub fn edit_hedging_strategy(ctx: Context<EditHedgingStrategy>, params: EditHedgingStrategyParams) -> anchor_lang::Result<()> {
+        edit_hedging_strategy_handler(ctx, params)
+    }
+
+    pub fn rebalance_hedge(ctx: Context<RebalanceHedge>) -> anchor_lang::Result<()> {
+        rebalance_hedge_handler(ctx)
+    }
+
+    pub fn mark_strategy(ctx: Context<MarkStrategy>) -> anchor_lang::Result<()> {
+        mark_strategy_handler(ctx)
+    }
+
+    pub fn check_strategy_risk(ctx: Context<CheckStrategyRisk>) -> anchor_lang::Result<()> {
+        check_strategy_risk_handler(ctx)
+    }
+
+    pub fn initialize_insurance_fund(ctx: Context<InitializeInsuranceFund>, yield_share_bps: u16) -> anchor_lang::Result<()> {
+        initialize_insurance_fund_handler(ctx, yield_share_bps)
+    }
+
+    pub fn edit_insurance_fund(ctx: Context<EditInsuranceFund>, yield_share_bps: u16) -> anchor_lang::Result<()> {
+        edit_insurance_fund_handler(ctx, yield_share_bps)
+    }
+
+    pub fn top_up_insurance_fund(ctx: Context<TopUpInsuranceFund>, amount: Amount) -> anchor_lang::Result<()> {
+        top_up_insurance_fund_handler(ctx, amount)
+    }
+
+    pub fn cover_strategy_loss(ctx: Context<CoverStrategyLoss>) -> anchor_lang::Result<()> {
+        cover_strategy_loss_handler(ctx)
+    }
+
+    pub fn adjust_hedge_ratio(ctx: Context<AdjustHedgeRatio>) -> anchor_lang::Result<()> {
+        adjust_hedge_ratio_handler(ctx)
+    }
+
+    pub fn initialize_hedging_audit_log(ctx: Context<InitializeHedgingAuditLog>) -> anchor_lang::Result<()> {
+        initialize_hedging_audit_log_handler(ctx)
+    }
 }
\ No newline at end of file
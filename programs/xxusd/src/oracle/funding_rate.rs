@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::adapters::perp::{load_perp_account, MarketData};
+use crate::error::XxusdError;
+
+/// Funding rate of a perp market's last funding period.
+pub struct FundingRate {
+    /// Scaled by `FUNDING_PRECISION`, positive when longs pay shorts.
+    pub rate: i64,
+    pub updated_at: i64,
+}
+
+/// A source of perp funding rates.
+pub trait FundingRateFeed {
+    fn funding_rate(&self) -> Result<FundingRate>;
+}
+
+/// Reads the funding rate published by a market of the `mock-perp` interface.
+pub struct PerpMarketFundingRateFeed<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub market: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> FundingRateFeed for PerpMarketFundingRateFeed<'a, 'info> {
+    fn funding_rate(&self) -> Result<FundingRate> {
+        let market: MarketData = load_perp_account(self.program, self.market)?;
+        Ok(FundingRate {
+            rate: market.funding_rate,
+            updated_at: market.funding_updated_at,
+        })
+    }
+}
+
+/// Reads the feed and rejects rates older than `max_age` seconds.
+pub fn get_fresh_funding_rate<F: FundingRateFeed>(feed: &F, max_age: i64, current_time: i64) -> Result<FundingRate> {
+    let funding_rate = feed.funding_rate()?;
+    let age = current_time
+        .checked_sub(funding_rate.updated_at)
+        .ok_or(XxusdError::MathOverflow)?;
+    require!(age <= max_age, XxusdError::StaleFundingRate);
+    Ok(funding_rate)
+}
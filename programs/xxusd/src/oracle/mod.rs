@@ -0,0 +1,3 @@
+pub mod funding_rate;
+
+pub use funding_rate::*;
@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::Amount;
 use crate::state::{safe_u128_to_u64, safe_u64_to_u128};
-use core::mem;
 
 #[account]
 pub struct Controller {
@@ -16,10 +15,59 @@ pub struct Controller {
     pub product_prices: Vec<(u64, Amount)>,
     pub locked_xxusd_supply: u64,
     pub max_products: u64,
+    /// Account layout version, 0 for controllers created before the field existed.
+    /// Fields added by later versions go after it.
+    pub version: u8,
+}
+
+/// Controller layouts older than [`CONTROLLER_VERSION`] are upgraded by `migrate_controller`.
+pub const CONTROLLER_VERSION: u8 = 1;
+
+/// The controller layout before `version` was added. Its fields are a prefix of [`Controller`].
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ControllerV0 {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub redeemable_mint: Pubkey,
+    pub xxusd_mint: Pubkey,
+    pub redeemable_circulating_supply: u64,
+    pub kamino_depository: Pubkey,
+    pub kamino_depository_weight_bps: u16,
+    pub is_frozen: bool,
+    pub product_prices: Vec<(u64, Amount)>,
+    pub locked_xxusd_supply: u64,
+    pub max_products: u64,
+}
+
+impl From<ControllerV0> for Controller {
+    fn from(controller: ControllerV0) -> Self {
+        Controller {
+            bump: controller.bump,
+            authority: controller.authority,
+            redeemable_mint: controller.redeemable_mint,
+            xxusd_mint: controller.xxusd_mint,
+            redeemable_circulating_supply: controller.redeemable_circulating_supply,
+            kamino_depository: controller.kamino_depository,
+            kamino_depository_weight_bps: controller.kamino_depository_weight_bps,
+            is_frozen: controller.is_frozen,
+            product_prices: controller.product_prices,
+            locked_xxusd_supply: controller.locked_xxusd_supply,
+            max_products: controller.max_products,
+            version: CONTROLLER_VERSION,
+        }
+    }
 }
 
 impl Controller {
-    pub const LEN: usize = 8 + mem::size_of::<Controller>();
+    /// Serialized size without the `product_prices` entries, discriminator included.
+    pub const BASE_LEN: usize = 8 + 1 + 32 + 32 + 32 + 8 + 32 + 2 + 1 + 4 + 8 + 8 + 1;
+    /// One `(product_id, price)` entry of `product_prices`.
+    pub const PRODUCT_PRICE_LEN: usize = 8 + 8;
+
+    /// Account space needed to hold `max_products` product prices.
+    pub fn space(max_products: u64) -> usize {
+        Self::BASE_LEN + Self::PRODUCT_PRICE_LEN * max_products as usize
+    }
 
     pub fn initialize(
         &mut self,
@@ -40,6 +88,7 @@ impl Controller {
         self.product_prices = Vec::new();
         self.locked_xxusd_supply = 0;
         self.max_products = max_products;
+        self.version = CONTROLLER_VERSION;
         Ok(())
     }
 
@@ -65,4 +114,33 @@ impl Controller {
         // 實現 load_mut 方法
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_fits_max_products() {
+        let max_products = 100;
+        let mut controller = Controller {
+            bump: 0,
+            authority: Pubkey::default(),
+            redeemable_mint: Pubkey::default(),
+            xxusd_mint: Pubkey::default(),
+            redeemable_circulating_supply: 0,
+            kamino_depository: Pubkey::default(),
+            kamino_depository_weight_bps: 0,
+            is_frozen: false,
+            product_prices: Vec::new(),
+            locked_xxusd_supply: 0,
+            max_products,
+            version: CONTROLLER_VERSION,
+        };
+        controller.product_prices = (0..max_products).map(|id| (id, Amount::new(id))).collect();
+
+        let mut data = Vec::new();
+        controller.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Controller::space(max_products));
+    }
+}
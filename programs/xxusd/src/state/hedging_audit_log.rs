@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+
+pub const HEDGING_AUDIT_LOG_LEN: usize = 16;
+/// Scale of a swap entry's price, amount out per amount in, in base units.
+pub const SWAP_PRICE_PRECISION: u128 = 1_000_000;
+
+/// Operations recorded in a strategy's audit log, `operation_type` of `HedgingStrategyEvent`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum HedgingOperationType {
+    #[default]
+    Deposit,
+    Withdraw,
+    Swap,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct HedgingAuditLogEntry {
+    pub operation_type: HedgingOperationType,
+    /// Collateral moved by a deposit or withdrawal, amount in of a swap.
+    pub amount: u64,
+    /// Perp mark price for a `PerpShort` strategy, reserve exchange rate for a `Lending`
+    /// strategy, amount out per amount in scaled by `SWAP_PRICE_PRECISION` for a swap.
+    pub price: u64,
+    pub slot: u64,
+    pub signer: Pubkey,
+}
+
+/// Last operations of one hedging strategy, kept on chain for operators to be audited
+/// without relying on transaction logs.
+#[account]
+pub struct HedgingAuditLog {
+    pub bump: u8,
+    pub hedging_strategy: Pubkey,
+    /// Operations recorded since the log was created.
+    pub operation_count: u64,
+    pub entries: [HedgingAuditLogEntry; HEDGING_AUDIT_LOG_LEN],
+    /// Next `entries` entry to overwrite.
+    pub entry_index: u8,
+}
+
+impl HedgingAuditLog {
+    pub const ENTRY_LEN: usize = 1 + 8 + 8 + 8 + 32;
+    pub const LEN: usize = 8 + 1 + 32 + 8 + Self::ENTRY_LEN * HEDGING_AUDIT_LOG_LEN + 1;
+
+    pub fn initialize(&mut self, bump: u8, hedging_strategy: Pubkey) -> Result<()> {
+        self.bump = bump;
+        self.hedging_strategy = hedging_strategy;
+        self.operation_count = 0;
+        self.entries = [HedgingAuditLogEntry::default(); HEDGING_AUDIT_LOG_LEN];
+        self.entry_index = 0;
+        Ok(())
+    }
+
+    /// Overwrites the oldest entry once the log is full.
+    pub fn append(&mut self, entry: HedgingAuditLogEntry) -> Result<()> {
+        let index = usize::from(self.entry_index) % HEDGING_AUDIT_LOG_LEN;
+        self.entries[index] = entry;
+        self.entry_index = ((index + 1) % HEDGING_AUDIT_LOG_LEN) as u8;
+        self.operation_count = self.operation_count.checked_add(1).ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Recorded entries, oldest first.
+    pub fn get_entries(&self) -> Vec<HedgingAuditLogEntry> {
+        let recorded = self.operation_count.min(HEDGING_AUDIT_LOG_LEN as u64) as usize;
+        let first = (usize::from(self.entry_index) + HEDGING_AUDIT_LOG_LEN - recorded) % HEDGING_AUDIT_LOG_LEN;
+        (0..recorded)
+            .map(|offset| self.entries[(first + offset) % HEDGING_AUDIT_LOG_LEN])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(amount: u64) -> HedgingAuditLogEntry {
+        HedgingAuditLogEntry {
+            operation_type: HedgingOperationType::Withdraw,
+            amount,
+            price: 1_000_000,
+            slot: amount,
+            signer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn test_append_wraps_around() {
+        let mut audit_log = HedgingAuditLog {
+            bump: 0,
+            hedging_strategy: Pubkey::default(),
+            operation_count: 0,
+            entries: [HedgingAuditLogEntry::default(); HEDGING_AUDIT_LOG_LEN],
+            entry_index: 0,
+        };
+        for amount in 1..=3 {
+            audit_log.append(entry(amount)).unwrap();
+        }
+        assert_eq!(audit_log.get_entries(), vec![entry(1), entry(2), entry(3)]);
+
+        let total = HEDGING_AUDIT_LOG_LEN as u64 + 5;
+        for amount in 4..=total {
+            audit_log.append(entry(amount)).unwrap();
+        }
+        let entries = audit_log.get_entries();
+        assert_eq!(audit_log.operation_count, total);
+        assert_eq!(entries.len(), HEDGING_AUDIT_LOG_LEN);
+        assert_eq!(entries[0], entry(6));
+        assert_eq!(entries[HEDGING_AUDIT_LOG_LEN - 1], entry(total));
+
+        let mut data = Vec::new();
+        audit_log.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), HedgingAuditLog::LEN);
+    }
+}
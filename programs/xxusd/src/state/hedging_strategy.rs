@@ -1,23 +1,144 @@
 use anchor_lang::prelude::*;
 use crate::state::Amount;
 use crate::state::u64_to_amount;
-use core::mem; // 添加這行
+use crate::error::XxusdError;
+use crate::utils::calculate_strategy_pnl::{calculate_short_pnl, calculate_unrealized_pnl};
+use crate::utils::calculate_lending_exchange_rate::{calculate_underlying_amount, EXCHANGE_RATE_PRECISION};
+use crate::BPS_POWER;
+
+/// How a hedging strategy puts its collateral to work.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum HedgingStrategyType {
+    /// Lends the collateral to a lending reserve.
+    #[default]
+    Lending,
+    /// Shorts a perp against the collateral to neutralize its price exposure.
+    PerpShort,
+}
 
 #[account]
 pub struct HedgingStrategy {
     pub bump: u8,
     pub controller: Pubkey,
+    pub strategy_id: u8,
+    pub strategy_type: HedgingStrategyType,
+    /// The collateral the strategy takes from its depository.
+    pub collateral_mint: Pubkey,
+    /// Share of the depository collateral the strategy aims to hold, 10000 = 100%.
+    pub target_allocation_bps: u16,
+    /// Hard cap on `deposited_amount`.
+    pub max_allocation: u64,
     pub deposited_amount: u64,
-    // ... 其他字段 ...
+    /// Deposits are refused while disabled, withdrawals are always allowed.
+    pub is_enabled: bool,
+    /// External program the strategy deploys to, the perp venue for `PerpShort`, the lending
+    /// program for `Lending`.
+    pub venue_program: Pubkey,
+    /// The venue's market, the perp market for `PerpShort`, the lending reserve for `Lending`.
+    pub venue_market: Pubkey,
+    /// Share of the collateral's price exposure to short, 10000 = fully hedged.
+    pub hedge_ratio_bps: u16,
+    /// How far the short may drift from its target, as a share of the collateral.
+    pub delta_tolerance_bps: u16,
+    /// Size of the short perp position, in collateral base units.
+    pub position_size: u64,
+    /// Average entry price of the short, in USD with 6 decimals.
+    pub entry_price: u64,
+    /// Funding paid by the short, negative when it received funding.
+    pub funding_paid: i64,
+    /// Reserve collateral tokens held by a `Lending` strategy.
+    pub lending_collateral_amount: u64,
+    /// Reserve liquidity per collateral token when last observed, scaled by `EXCHANGE_RATE_PRECISION`.
+    pub exchange_rate: u128,
+    pub exchange_rate_updated_at: i64,
+    /// Last mark price of the perp, in USD with 6 decimals.
+    pub mark_price: u64,
+    // PnL, in collateral base units
+    /// Profit taken out of the strategy: interest withdrawn and short gains realized by buybacks.
+    pub realized_pnl: i64,
+    /// Net asset value minus principal at the last mark.
+    pub unrealized_pnl: i64,
+    /// Lending interest not yet withdrawn at the last mark.
+    pub accrued_yield: u64,
+    pub last_marked_at: i64,
+    // Risk limits, zero for no limit
+    /// Max share of the collateral, held by the depository and the strategy, in the strategy.
+    pub max_collateral_share_bps: u16,
+    /// Max fall of `realized_pnl + unrealized_pnl` from `high_water_mark`, in bps of principal.
+    pub max_drawdown_bps: u16,
+    /// Max short size over net asset value, 10000 = 1x.
+    pub max_leverage_bps: u16,
+    /// Highest `realized_pnl + unrealized_pnl` seen at a mark.
+    pub high_water_mark: i64,
+    /// Set when a risk limit is breached: deposits are refused and the short is unwound.
+    pub is_withdraw_only: bool,
+    /// Realized losses not yet covered by the insurance fund, in collateral base units.
+    pub uncovered_loss: u64,
+    // Funding policy, applied to `hedge_ratio_bps` by `adjust_hedge_ratio`
+    /// Short funding cost per period, in bps, tolerated before the hedge is reduced.
+    pub funding_rate_threshold_bps: u16,
+    /// Hedge ratio cut, in bps, per bps of funding cost above the threshold. Zero disables the policy.
+    pub funding_sensitivity_bps: u16,
+    /// Floor of the hedge ratio under the policy.
+    pub min_hedge_ratio_bps: u16,
+    /// Oldest funding rate, in seconds, the policy acts on.
+    pub max_funding_rate_age: i64,
+    /// Funding rate behind the current hedge ratio, scaled by `FUNDING_PRECISION`.
+    pub last_funding_rate: i64,
 }
 
 impl HedgingStrategy {
-    pub const LEN: usize = 8 + mem::size_of::<HedgingStrategy>();
+    pub const LEN: usize = 8 + 1 + 32 + 1 + 1 + 32 + 2 + 8 + 8 + 1 + 32 + 32 + 2 + 2 + 8 + 8 + 8 + 8 + 16 + 8 + 8 + 8 + 8 + 8 + 8 + 2 + 2 + 2 + 8 + 1 + 8 + 2 + 2 + 2 + 8 + 8;
 
-    pub fn initialize(&mut self, bump: u8, controller: Pubkey) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        controller: Pubkey,
+        strategy_id: u8,
+        strategy_type: HedgingStrategyType,
+        collateral_mint: Pubkey,
+        target_allocation_bps: u16,
+        max_allocation: u64,
+        venue_program: Pubkey,
+        venue_market: Pubkey,
+        delta_tolerance_bps: u16,
+    ) -> Result<()> {
         self.bump = bump;
         self.controller = controller;
+        self.strategy_id = strategy_id;
+        self.strategy_type = strategy_type;
+        self.collateral_mint = collateral_mint;
+        self.target_allocation_bps = target_allocation_bps;
+        self.max_allocation = max_allocation;
         self.deposited_amount = 0;
+        self.is_enabled = true;
+        self.venue_program = venue_program;
+        self.venue_market = venue_market;
+        self.hedge_ratio_bps = BPS_POWER as u16;
+        self.delta_tolerance_bps = delta_tolerance_bps;
+        self.position_size = 0;
+        self.entry_price = 0;
+        self.funding_paid = 0;
+        self.lending_collateral_amount = 0;
+        self.exchange_rate = EXCHANGE_RATE_PRECISION;
+        self.exchange_rate_updated_at = 0;
+        self.mark_price = 0;
+        self.realized_pnl = 0;
+        self.unrealized_pnl = 0;
+        self.accrued_yield = 0;
+        self.last_marked_at = 0;
+        self.max_collateral_share_bps = 0;
+        self.max_drawdown_bps = 0;
+        self.max_leverage_bps = 0;
+        self.high_water_mark = 0;
+        self.is_withdraw_only = false;
+        self.uncovered_loss = 0;
+        self.funding_rate_threshold_bps = 0;
+        self.funding_sensitivity_bps = 0;
+        self.min_hedge_ratio_bps = BPS_POWER as u16;
+        self.max_funding_rate_age = 0;
+        self.last_funding_rate = 0;
         Ok(())
     }
 
@@ -29,5 +150,118 @@ impl HedgingStrategy {
         self.deposited_amount = amount.value();
     }
 
-    // ... 其他方法 ...
-}
\ No newline at end of file
+    pub fn record_exchange_rate(&mut self, lending_collateral_amount: u64, exchange_rate: u128, current_time: i64) {
+        self.lending_collateral_amount = lending_collateral_amount;
+        self.exchange_rate = exchange_rate;
+        self.exchange_rate_updated_at = current_time;
+    }
+
+    /// Marks the strategy to market from `vault_amount`, the last recorded exchange rate and
+    /// the last observed perp position and mark price.
+    pub fn mark(&mut self, vault_amount: u64, current_time: i64) -> Result<()> {
+        let lending_underlying_amount = self.get_lending_underlying_amount()?;
+        let assets_amount = vault_amount
+            .checked_add(lending_underlying_amount)
+            .ok_or(XxusdError::MathOverflow)?;
+        let short_pnl = calculate_short_pnl(self.position_size, self.entry_price, self.mark_price)?;
+
+        self.unrealized_pnl = calculate_unrealized_pnl(assets_amount, short_pnl, self.funding_paid, self.deposited_amount)?;
+        self.accrued_yield = if self.strategy_type == HedgingStrategyType::Lending {
+            assets_amount.saturating_sub(self.deposited_amount)
+        } else {
+            0
+        };
+        self.last_marked_at = current_time;
+        self.high_water_mark = self.high_water_mark.max(self.get_total_pnl()?);
+        Ok(())
+    }
+
+    pub fn get_total_pnl(&self) -> Result<i64> {
+        self.realized_pnl
+            .checked_add(self.unrealized_pnl)
+            .ok_or(XxusdError::MathOverflow.into())
+    }
+
+    /// Principal plus unrealized PnL at the last mark.
+    pub fn get_net_asset_value(&self) -> Result<i64> {
+        i64::try_from(self.deposited_amount)
+            .ok()
+            .and_then(|deposited_amount| deposited_amount.checked_add(self.unrealized_pnl))
+            .ok_or(XxusdError::MathOverflow.into())
+    }
+
+    pub fn set_risk_limits(&mut self, max_collateral_share_bps: u16, max_drawdown_bps: u16, max_leverage_bps: u16) {
+        self.max_collateral_share_bps = max_collateral_share_bps;
+        self.max_drawdown_bps = max_drawdown_bps;
+        self.max_leverage_bps = max_leverage_bps;
+    }
+
+    /// Whether the strategy may take new deposits or grow its short.
+    pub fn can_increase_exposure(&self) -> bool {
+        self.is_enabled && !self.is_withdraw_only
+    }
+
+    /// `pnl` must have been settled in the strategy's collateral: realized losses are also
+    /// queued for coverage by the insurance fund.
+    pub fn add_realized_pnl(&mut self, pnl: i64) -> Result<()> {
+        self.realized_pnl = self.realized_pnl.checked_add(pnl).ok_or(XxusdError::MathOverflow)?;
+        if pnl < 0 {
+            self.uncovered_loss = self.uncovered_loss
+                .checked_add(pnl.unsigned_abs())
+                .ok_or(XxusdError::MathOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Only the collateral vault and lent collateral are valued by `mark` and withdrawals, so
+    /// swaps may only convert other holdings into collateral.
+    pub fn is_swap_allowed(&self, source_mint: &Pubkey, destination_mint: &Pubkey) -> bool {
+        *destination_mint == self.collateral_mint && *source_mint != self.collateral_mint
+    }
+
+    /// Liquidity the strategy's reserve collateral was worth at the last recorded exchange rate.
+    pub fn get_lending_underlying_amount(&self) -> Result<u64> {
+        calculate_underlying_amount(self.lending_collateral_amount, self.exchange_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::calculate_strategy_risk::calculate_drawdown_bps;
+
+    fn lending_strategy(collateral_mint: Pubkey) -> HedgingStrategy {
+        let data = vec![0u8; HedgingStrategy::LEN];
+        let mut hedging_strategy = HedgingStrategy::try_deserialize_unchecked(&mut &data[..]).unwrap();
+        hedging_strategy
+            .initialize(0, Pubkey::default(), 0, HedgingStrategyType::Lending, collateral_mint, 0, 0, Pubkey::default(), Pubkey::default(), 0)
+            .unwrap();
+        hedging_strategy
+    }
+
+    #[test]
+    fn test_mark_after_swap() {
+        let collateral_mint = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+        let mut hedging_strategy = lending_strategy(collateral_mint);
+        hedging_strategy.deposited_amount = 1_000;
+        hedging_strategy.mark(1_000, 0).unwrap();
+        assert_eq!(hedging_strategy.unrealized_pnl, 0);
+
+        // Collateral cannot be swapped out into holdings `mark` does not value
+        assert!(!hedging_strategy.is_swap_allowed(&collateral_mint, &other_mint));
+        assert!(!hedging_strategy.is_swap_allowed(&other_mint, &other_mint));
+        assert!(hedging_strategy.is_swap_allowed(&other_mint, &collateral_mint));
+
+        // Swapping other holdings into the vault only adds value
+        hedging_strategy.mark(1_040, 1).unwrap();
+        assert_eq!(hedging_strategy.unrealized_pnl, 40);
+        let drawdown_bps = calculate_drawdown_bps(
+            hedging_strategy.high_water_mark,
+            hedging_strategy.get_total_pnl().unwrap(),
+            hedging_strategy.deposited_amount,
+        )
+        .unwrap();
+        assert_eq!(drawdown_bps, 0);
+    }
+}
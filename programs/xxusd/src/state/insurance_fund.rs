@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+
+/// Buffer of one collateral that absorbs hedging strategy losses before they reach xxUSD holders.
+#[account]
+pub struct InsuranceFund {
+    pub bump: u8,
+    pub controller: Pubkey,
+    pub collateral_mint: Pubkey,
+    /// Share of the profit realized by strategies, lending interest and perp gains, that is
+    /// paid into the fund.
+    pub yield_share_bps: u16,
+    pub total_deposited: u64,
+    pub total_drawn: u64,
+}
+
+impl InsuranceFund {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 2 + 8 + 8;
+
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        controller: Pubkey,
+        collateral_mint: Pubkey,
+        yield_share_bps: u16,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.controller = controller;
+        self.collateral_mint = collateral_mint;
+        self.yield_share_bps = yield_share_bps;
+        self.total_deposited = 0;
+        self.total_drawn = 0;
+        Ok(())
+    }
+
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited.checked_add(amount).ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_draw(&mut self, amount: u64) -> Result<()> {
+        self.total_drawn = self.total_drawn.checked_add(amount).ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+}
@@ -9,17 +9,26 @@ pub struct KaminoDepository {
     pub redeemable_amount_under_management_cap: u128,
     pub minting_fee_in_bps: u16,
     pub redeeming_fee_in_bps: u16,
+    /// Decimals of the registered collateral mint.
+    pub collateral_mint_decimals: u8,
+    /// Price feed used to value the collateral.
+    pub oracle: Pubkey,
+    /// Whether minting and redeeming against this collateral is allowed.
+    pub is_enabled: bool,
     // 添加其他必要的字段...
 }
 
 impl KaminoDepository {
-    pub const LEN: usize = 8 + 1 + 32 + 32 + 16 + 16 + 2 + 2;
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 16 + 16 + 2 + 2 + 1 + 32 + 1;
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         bump: u8,
         controller: Pubkey,
         collateral_mint: Pubkey,
+        collateral_mint_decimals: u8,
+        oracle: Pubkey,
         redeemable_amount_under_management_cap: u128,
         minting_fee_in_bps: u16,
         redeeming_fee_in_bps: u16,
@@ -31,6 +40,9 @@ impl KaminoDepository {
         self.redeemable_amount_under_management_cap = redeemable_amount_under_management_cap;
         self.minting_fee_in_bps = minting_fee_in_bps;
         self.redeeming_fee_in_bps = redeeming_fee_in_bps;
+        self.collateral_mint_decimals = collateral_mint_decimals;
+        self.oracle = oracle;
+        self.is_enabled = true;
         Ok(())
     }
 
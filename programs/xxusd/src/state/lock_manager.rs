@@ -1,33 +1,73 @@
 use anchor_lang::prelude::*;
-use crate::state::{Amount, Timestamp};
+use crate::state::Amount;
 use crate::state::u64_to_amount;
+use crate::state::lock_position::LockPosition;
+use crate::error::XxusdError;
+use crate::utils::{calculate_accrued_rewards, calculate_reward_per_share_increase, calculate_weighted_amount, REWARD_PER_SHARE_PRECISION};
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
-pub struct Lock {
-    pub amount: Amount,
-    pub lock_time: Timestamp,
-    pub lock_period: Timestamp,
+/// Where penalties charged by `early_release` are sent.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum PenaltyDestination {
+    /// The lock reward vault, shared by the remaining lockers.
+    #[default]
+    RewardPool,
+    /// The configured treasury token account.
+    Treasury,
 }
 
+/// Global lock bookkeeping. Owns the lock vault; individual locks live in
+/// their own [`LockPosition`](crate::state::LockPosition) accounts.
 #[account]
 pub struct LockManager {
     pub bump: u8,
     pub controller: Pubkey,
     pub total_locked_amount: u64,
-    pub locks: Vec<Lock>,
+    /// Token account holding rewards for lockers.
+    pub lock_reward_vault: Pubkey,
+    /// Penalty charged on the unvested amount at the very start of a lock, decaying linearly to zero.
+    pub early_release_penalty_bps: u16,
+    pub penalty_destination: PenaltyDestination,
+    /// xxUSD token account receiving penalties when `penalty_destination` is `Treasury`.
+    pub treasury: Pubkey,
+    /// Rewards sitting in the reward vault that have not been distributed to lockers yet.
+    pub undistributed_rewards: u64,
+    /// Rewards accrued per unit of weighted lock, scaled by `REWARD_PER_SHARE_PRECISION`.
+    pub acc_reward_per_share: u128,
+    /// Sum of every lock position's `weighted_amount`.
+    pub total_weighted_amount: u128,
 }
 
 impl LockManager {
-    pub const LEN: usize = 8 + 1 + 32 + 8 + 4 + (32 * 10); // 假設最多存儲10個鎖定記錄
+    pub const LEN: usize = 8 + 1 + 32 + 8 + 32 + 2 + 1 + 32 + 8 + 16 + 16;
 
-    pub fn initialize(&mut self, bump: u8, controller: Pubkey) -> anchor_lang::Result<()> {
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        controller: Pubkey,
+        lock_reward_vault: Pubkey,
+        early_release_penalty_bps: u16,
+    ) -> anchor_lang::Result<()> {
         self.bump = bump;
         self.controller = controller;
         self.total_locked_amount = 0;
-        self.locks = Vec::new();
+        self.lock_reward_vault = lock_reward_vault;
+        self.early_release_penalty_bps = early_release_penalty_bps;
+        self.penalty_destination = PenaltyDestination::RewardPool;
+        self.treasury = Pubkey::default();
+        self.undistributed_rewards = 0;
+        self.acc_reward_per_share = 0;
+        self.total_weighted_amount = 0;
         Ok(())
     }
 
+    /// The token account penalties must currently be sent to.
+    pub fn get_penalty_destination_account(&self) -> Pubkey {
+        match self.penalty_destination {
+            PenaltyDestination::RewardPool => self.lock_reward_vault,
+            PenaltyDestination::Treasury => self.treasury,
+        }
+    }
+
     pub fn get_total_locked_amount(&self) -> Amount {
         u64_to_amount(self.total_locked_amount)
     }
@@ -36,5 +76,47 @@ impl LockManager {
         self.total_locked_amount = amount.value();
     }
 
+    /// Adds `amount` to the undistributed rewards and shares them over the current weighted lock.
+    /// Rewards stay undistributed while nothing is locked, and rounding dust is carried over.
+    pub fn distribute_rewards(&mut self, amount: u64) -> anchor_lang::Result<()> {
+        self.undistributed_rewards = self
+            .undistributed_rewards
+            .checked_add(amount)
+            .ok_or(XxusdError::Overflow)?;
+        if self.total_weighted_amount == 0 || self.undistributed_rewards == 0 {
+            return Ok(());
+        }
+
+        let increase = calculate_reward_per_share_increase(self.undistributed_rewards, self.total_weighted_amount)?;
+        let distributed = increase
+            .checked_mul(self.total_weighted_amount)
+            .ok_or(XxusdError::MathOverflow)?
+            / REWARD_PER_SHARE_PRECISION;
+        self.acc_reward_per_share = self
+            .acc_reward_per_share
+            .checked_add(increase)
+            .ok_or(XxusdError::MathOverflow)?;
+        self.undistributed_rewards = self
+            .undistributed_rewards
+            .checked_sub(u64::try_from(distributed).map_err(|_| XxusdError::MathOverflow)?)
+            .ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Recomputes the reward weight of a position after its locked amount or multiplier changed.
+    /// The position must have been settled with `LockPosition::settle_rewards` beforehand.
+    pub fn update_position_weight(&mut self, lock_position: &mut LockPosition) -> anchor_lang::Result<()> {
+        let locked_amount = lock_position.get_locked_amount()?;
+        let weighted_amount = calculate_weighted_amount(locked_amount.value(), lock_position.reward_multiplier_bps)?;
+        self.total_weighted_amount = self
+            .total_weighted_amount
+            .checked_sub(lock_position.weighted_amount)
+            .and_then(|total| total.checked_add(weighted_amount))
+            .ok_or(XxusdError::MathOverflow)?;
+        lock_position.weighted_amount = weighted_amount;
+        lock_position.reward_debt = calculate_accrued_rewards(weighted_amount, self.acc_reward_per_share)?;
+        Ok(())
+    }
+
     // 添加其他必要的方法
-}
\ No newline at end of file
+}
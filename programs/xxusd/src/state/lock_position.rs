@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::state::{Amount, Timestamp};
+use crate::state::u64_to_amount;
+use crate::error::XxusdError;
+use crate::utils::{calculate_accrued_rewards, VestingSchedule, SECONDS_PER_DAY};
+use crate::utils::maths::checked_sub;
+
+/// Granularity at which a lock position vests.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ReleaseInterval {
+    #[default]
+    Second,
+    Day,
+}
+
+impl ReleaseInterval {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            ReleaseInterval::Second => 1,
+            ReleaseInterval::Day => SECONDS_PER_DAY,
+        }
+    }
+}
+
+/// A single user's lock, seeded by owner and a user chosen nonce.
+#[account]
+pub struct LockPosition {
+    pub bump: u8,
+    /// The account that created the position, only used for its address. Whoever holds
+    /// the receipt token controls the position.
+    pub owner: Pubkey,
+    pub lock_manager: Pubkey,
+    pub nonce: u64,
+    pub amount: u64,
+    pub start_time: i64,
+    pub lock_period: i64,
+    pub released_amount: u64,
+    pub cliff_period: i64,
+    pub release_interval: ReleaseInterval,
+    pub tier_id: u8,
+    /// Reward weight copied from the lock tier when the position was created, 10000 = 1x.
+    pub reward_multiplier_bps: u16,
+    /// Unreleased amount times the reward multiplier, the position's share of lock rewards.
+    pub weighted_amount: u128,
+    /// Rewards already accounted for at the lock manager's current `acc_reward_per_share`.
+    pub reward_debt: u128,
+    /// Rewards earned but not claimed yet.
+    pub pending_rewards: u64,
+    /// Mint of the single receipt token representing this position.
+    pub receipt_mint: Pubkey,
+    /// Amount counted by the voting escrow, the unreleased amount when it was last updated.
+    pub voting_amount: u64,
+    /// When this position stops counting towards voting power.
+    pub voting_end_time: i64,
+    /// May release vested xxUSD, always to the receipt holder. Set by the receipt holder.
+    pub delegate: Option<Pubkey>,
+}
+
+impl LockPosition {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 2 + 16 + 16 + 8 + 32 + 8 + 8 + 33;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        owner: Pubkey,
+        lock_manager: Pubkey,
+        nonce: u64,
+        amount: Amount,
+        start_time: Timestamp,
+        lock_period: Timestamp,
+        cliff_period: Timestamp,
+        release_interval: ReleaseInterval,
+        tier_id: u8,
+        reward_multiplier_bps: u16,
+        receipt_mint: Pubkey,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.owner = owner;
+        self.lock_manager = lock_manager;
+        self.nonce = nonce;
+        self.amount = amount.value();
+        self.start_time = start_time.value();
+        self.lock_period = lock_period.value();
+        self.released_amount = 0;
+        self.cliff_period = cliff_period.value();
+        self.release_interval = release_interval;
+        self.tier_id = tier_id;
+        self.reward_multiplier_bps = reward_multiplier_bps;
+        self.weighted_amount = 0;
+        self.reward_debt = 0;
+        self.pending_rewards = 0;
+        self.receipt_mint = receipt_mint;
+        self.voting_amount = 0;
+        self.voting_end_time = 0;
+        self.delegate = None;
+        Ok(())
+    }
+
+    pub fn get_end_time(&self) -> i64 {
+        self.start_time.saturating_add(self.lock_period)
+    }
+
+    /// Restarts vesting at `current_time` for `amount` over `lock_period`, keeping whatever is
+    /// left of the cliff. Anything vested under the old schedule must be released beforehand.
+    pub fn restart_vesting(&mut self, current_time: i64, amount: Amount, lock_period: i64) -> Result<()> {
+        require!(lock_period > 0, XxusdError::InvalidLockPeriod);
+        let cliff_end_time = self.start_time.saturating_add(self.cliff_period);
+        self.cliff_period = cliff_end_time.saturating_sub(current_time).clamp(0, lock_period);
+        self.start_time = current_time;
+        self.lock_period = lock_period;
+        self.amount = amount.value();
+        self.released_amount = 0;
+        Ok(())
+    }
+
+    pub fn get_vesting_schedule(&self) -> VestingSchedule {
+        VestingSchedule {
+            total_amount: self.amount,
+            start_time: self.start_time,
+            cliff_period: self.cliff_period,
+            vesting_period: self.lock_period,
+            release_interval: self.release_interval.seconds(),
+        }
+    }
+
+    pub fn get_amount(&self) -> Amount {
+        u64_to_amount(self.amount)
+    }
+
+    pub fn get_released_amount(&self) -> Amount {
+        u64_to_amount(self.released_amount)
+    }
+
+    pub fn set_released_amount(&mut self, amount: Amount) {
+        self.released_amount = amount.value();
+    }
+
+    pub fn is_fully_released(&self) -> bool {
+        self.released_amount >= self.amount
+    }
+
+    /// Amount still held in the lock vault for this position.
+    pub fn get_locked_amount(&self) -> Result<Amount> {
+        checked_sub(self.get_amount(), self.get_released_amount())
+    }
+
+    /// Moves rewards accrued since the last settlement into `pending_rewards`.
+    pub fn settle_rewards(&mut self, acc_reward_per_share: u128) -> Result<()> {
+        let accrued_rewards = calculate_accrued_rewards(self.weighted_amount, acc_reward_per_share)?;
+        let new_rewards = accrued_rewards
+            .checked_sub(self.reward_debt)
+            .ok_or(XxusdError::MathOverflow)?;
+        self.pending_rewards = self
+            .pending_rewards
+            .checked_add(u64::try_from(new_rewards).map_err(|_| XxusdError::MathOverflow)?)
+            .ok_or(XxusdError::MathOverflow)?;
+        self.reward_debt = accrued_rewards;
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// An admin configured lock duration that users pick when locking xxUSD.
+#[account]
+pub struct LockTier {
+    pub bump: u8,
+    pub lock_manager: Pubkey,
+    pub tier_id: u8,
+    pub lock_period: i64,
+    /// Weight applied to positions of this tier for reward distribution, 10000 = 1x.
+    pub reward_multiplier_bps: u16,
+    pub is_enabled: bool,
+}
+
+impl LockTier {
+    pub const LEN: usize = 8 + 1 + 32 + 1 + 8 + 2 + 1;
+
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        lock_manager: Pubkey,
+        tier_id: u8,
+        lock_period: i64,
+        reward_multiplier_bps: u16,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.lock_manager = lock_manager;
+        self.tier_id = tier_id;
+        self.lock_period = lock_period;
+        self.reward_multiplier_bps = reward_multiplier_bps;
+        self.is_enabled = true;
+        Ok(())
+    }
+}
@@ -4,13 +4,37 @@ use std::convert::TryInto;
 
 pub mod controller;
 pub mod lock_manager;
+pub mod lock_position;
+pub mod lock_tier;
 pub mod hedging_strategy;
 pub mod kamino_depository;
-
-pub use controller::Controller;
-pub use lock_manager::LockManager;
-pub use hedging_strategy::HedgingStrategy;
+pub mod term_depository;
+pub mod queued_redemption;
+pub mod staking_vault;
+pub mod staking_withdrawal_request;
+pub mod voting_escrow;
+pub mod vesting_grant;
+pub mod swap_program;
+pub mod insurance_fund;
+pub mod hedging_audit_log;
+pub mod perp_hedge;
+
+pub use controller::{Controller, ControllerV0, CONTROLLER_VERSION};
+pub use lock_manager::{LockManager, PenaltyDestination};
+pub use lock_position::{LockPosition, ReleaseInterval};
+pub use lock_tier::LockTier;
+pub use hedging_strategy::{HedgingStrategy, HedgingStrategyType};
 pub use kamino_depository::KaminoDepository;
+pub use term_depository::TermDepository;
+pub use queued_redemption::QueuedRedemption;
+pub use staking_vault::StakingVault;
+pub use staking_withdrawal_request::StakingWithdrawalRequest;
+pub use voting_escrow::{VotingEscrow, VotingPowerCheckpoint};
+pub use vesting_grant::VestingGrant;
+pub use swap_program::SwapProgram;
+pub use insurance_fund::InsuranceFund;
+pub use hedging_audit_log::{HedgingAuditLog, HedgingAuditLogEntry, HedgingOperationType};
+pub use perp_hedge::PerpHedge;
 
 /// 表示金額的自定義類型
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
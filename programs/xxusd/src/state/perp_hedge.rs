@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// The one `PerpShort` strategy allowed per collateral mint. It sizes its short against all
+/// the collateral of the mint, so a second one would hedge the same collateral twice.
+#[account]
+pub struct PerpHedge {
+    pub bump: u8,
+    pub collateral_mint: Pubkey,
+    pub strategy_id: u8,
+}
+
+impl PerpHedge {
+    pub const LEN: usize = 8 + 1 + 32 + 1;
+
+    pub fn initialize(&mut self, bump: u8, collateral_mint: Pubkey, strategy_id: u8) -> Result<()> {
+        self.bump = bump;
+        self.collateral_mint = collateral_mint;
+        self.strategy_id = strategy_id;
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+/// A redemption waiting for its term depository to unlock.
+#[account]
+pub struct QueuedRedemption {
+    pub bump: u8,
+    pub user: Pubkey,
+    pub term_depository: Pubkey,
+    /// Distinguishes the user's queued redemptions against the same term depository.
+    pub nonce: u64,
+    pub redeemable_amount: u64,
+    pub collateral_amount: u64,
+    pub queued_time: i64,
+}
+
+impl QueuedRedemption {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 8;
+}
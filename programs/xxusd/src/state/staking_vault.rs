@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::error::XxusdError;
+use crate::state::Amount;
+use crate::state::u64_to_amount;
+
+/// Share based xxUSD vault. Deposits mint sxxUSD at the current share price and
+/// yield added through `add_staking_yield` raises the price for every holder.
+#[account]
+pub struct StakingVault {
+    pub bump: u8,
+    pub controller: Pubkey,
+    /// The sxxUSD mint, a PDA whose mint authority is this vault.
+    pub share_mint: Pubkey,
+    /// xxUSD token account holding the staked assets and pending withdrawals.
+    pub vault: Pubkey,
+    /// xxUSD backing the outstanding sxxUSD. Tokens sent to the vault directly are not counted.
+    pub total_assets: u64,
+    /// xxUSD set aside for withdrawal requests still cooling down.
+    pub pending_withdrawal_amount: u64,
+    /// Seconds between a withdrawal request and its claim, 0 allows instant withdrawals.
+    pub cooldown_period: i64,
+}
+
+impl StakingVault {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 32 + 8 + 8 + 8;
+
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        controller: Pubkey,
+        share_mint: Pubkey,
+        vault: Pubkey,
+        cooldown_period: i64,
+    ) -> Result<()> {
+        require!(cooldown_period >= 0, XxusdError::InvalidCooldownPeriod);
+        self.bump = bump;
+        self.controller = controller;
+        self.share_mint = share_mint;
+        self.vault = vault;
+        self.total_assets = 0;
+        self.pending_withdrawal_amount = 0;
+        self.cooldown_period = cooldown_period;
+        Ok(())
+    }
+
+    pub fn get_total_assets(&self) -> Amount {
+        u64_to_amount(self.total_assets)
+    }
+
+    pub fn set_total_assets(&mut self, amount: Amount) {
+        self.total_assets = amount.value();
+    }
+
+    pub fn get_pending_withdrawal_amount(&self) -> Amount {
+        u64_to_amount(self.pending_withdrawal_amount)
+    }
+
+    pub fn set_pending_withdrawal_amount(&mut self, amount: Amount) {
+        self.pending_withdrawal_amount = amount.value();
+    }
+}
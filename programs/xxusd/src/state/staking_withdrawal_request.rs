@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// xxUSD owed to a user who burned sxxUSD, claimable once the cooldown has passed.
+#[account]
+pub struct StakingWithdrawalRequest {
+    pub bump: u8,
+    pub user: Pubkey,
+    pub staking_vault: Pubkey,
+    pub amount: u64,
+    pub claimable_time: i64,
+}
+
+impl StakingWithdrawalRequest {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8;
+
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        user: Pubkey,
+        staking_vault: Pubkey,
+        amount: u64,
+        claimable_time: i64,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.user = user;
+        self.staking_vault = staking_vault;
+        self.amount = amount;
+        self.claimable_time = claimable_time;
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+/// Whitelists an AMM program for hedging strategy swaps.
+#[account]
+pub struct SwapProgram {
+    pub bump: u8,
+    pub controller: Pubkey,
+    pub program_id: Pubkey,
+    pub is_enabled: bool,
+}
+
+impl SwapProgram {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 1;
+
+    pub fn initialize(&mut self, bump: u8, controller: Pubkey, program_id: Pubkey) -> Result<()> {
+        self.bump = bump;
+        self.controller = controller;
+        self.program_id = program_id;
+        self.is_enabled = true;
+        Ok(())
+    }
+}
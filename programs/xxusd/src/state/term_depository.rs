@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::XxusdError;
+
+/// A depository whose collateral sits in a term deposit or staked position
+/// and can only be withdrawn once `unlock_time` has passed.
+#[account]
+pub struct TermDepository {
+    pub bump: u8,
+    pub controller: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub redeemable_amount_under_management: u128,
+    pub redeemable_amount_under_management_cap: u128,
+    /// Unix timestamp before which the collateral cannot be withdrawn.
+    pub unlock_time: i64,
+    /// Whether the underlying position can currently be unwound.
+    pub is_liquid: bool,
+    /// Redeemable amount already promised to queued redemptions.
+    pub queued_redeemable_amount: u128,
+}
+
+impl TermDepository {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 16 + 16 + 8 + 1 + 16;
+
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        controller: Pubkey,
+        collateral_mint: Pubkey,
+        redeemable_amount_under_management_cap: u128,
+        unlock_time: i64,
+        is_liquid: bool,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.controller = controller;
+        self.collateral_mint = collateral_mint;
+        self.redeemable_amount_under_management = 0;
+        self.redeemable_amount_under_management_cap = redeemable_amount_under_management_cap;
+        self.unlock_time = unlock_time;
+        self.is_liquid = is_liquid;
+        self.queued_redeemable_amount = 0;
+        Ok(())
+    }
+
+    /// Whether queued redemptions can be claimed: liquid and past its unlock time.
+    pub fn is_redeemable(&self, current_time: i64) -> bool {
+        self.is_liquid && current_time >= self.unlock_time
+    }
+
+    /// Redeemable amount under management not yet promised to a queued redemption.
+    pub fn get_unqueued_redeemable_amount(&self) -> Result<u128> {
+        Ok(self
+            .redeemable_amount_under_management
+            .checked_sub(self.queued_redeemable_amount)
+            .ok_or(XxusdError::Overflow)?)
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::Amount;
+use crate::state::u64_to_amount;
+use crate::utils::{calculate_vested_amount, VestingSchedule};
+
+/// Admin created vesting grant, funded from the treasury and held in the lock vault.
+#[account]
+pub struct VestingGrant {
+    pub bump: u8,
+    pub lock_manager: Pubkey,
+    pub recipient: Pubkey,
+    pub grant_id: u64,
+    pub total_amount: u64,
+    pub released_amount: u64,
+    pub start_time: i64,
+    pub cliff_period: i64,
+    pub duration: i64,
+    pub revocable: bool,
+    /// Vesting stops here once the grant is revoked, 0 while it is not.
+    pub revoked_time: i64,
+}
+
+impl VestingGrant {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 8;
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        lock_manager: Pubkey,
+        recipient: Pubkey,
+        grant_id: u64,
+        total_amount: Amount,
+        start_time: i64,
+        cliff_period: i64,
+        duration: i64,
+        revocable: bool,
+    ) -> Result<()> {
+        self.bump = bump;
+        self.lock_manager = lock_manager;
+        self.recipient = recipient;
+        self.grant_id = grant_id;
+        self.total_amount = total_amount.value();
+        self.released_amount = 0;
+        self.start_time = start_time;
+        self.cliff_period = cliff_period;
+        self.duration = duration;
+        self.revocable = revocable;
+        self.revoked_time = 0;
+        Ok(())
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_time != 0
+    }
+
+    pub fn get_vesting_schedule(&self) -> VestingSchedule {
+        VestingSchedule {
+            total_amount: self.total_amount,
+            start_time: self.start_time,
+            cliff_period: self.cliff_period,
+            vesting_period: self.duration,
+            release_interval: 1,
+        }
+    }
+
+    /// Vested amount at `current_time`, frozen at the revocation time for revoked grants.
+    pub fn get_vested_amount(&self, current_time: i64) -> Result<Amount> {
+        let vesting_time = if self.is_revoked() {
+            current_time.min(self.revoked_time)
+        } else {
+            current_time
+        };
+        Ok(u64_to_amount(calculate_vested_amount(&self.get_vesting_schedule(), vesting_time)?))
+    }
+
+    pub fn get_total_amount(&self) -> Amount {
+        u64_to_amount(self.total_amount)
+    }
+
+    pub fn get_released_amount(&self) -> Amount {
+        u64_to_amount(self.released_amount)
+    }
+
+    pub fn set_released_amount(&mut self, amount: Amount) {
+        self.released_amount = amount.value();
+    }
+}
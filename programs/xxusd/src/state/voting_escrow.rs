@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::error::XxusdError;
+use crate::state::lock_position::LockPosition;
+use crate::utils::{calculate_voting_end_time, round_down_to_week, MAX_VOTING_LOCK_PERIOD, MAX_VOTING_LOCK_WEEKS, SECONDS_PER_WEEK};
+
+/// Every pending voting end time falls within this many week slots of the last checkpoint.
+pub const SLOPE_CHANGE_SLOTS: usize = MAX_VOTING_LOCK_WEEKS as usize + 1;
+pub const VOTING_POWER_HISTORY_LEN: usize = 26;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default)]
+pub struct VotingPowerCheckpoint {
+    pub timestamp: i64,
+    pub total_voting_power: u64,
+}
+
+/// Total supply of ve voting power across all lock positions, kept as a bias decaying
+/// by a slope that drops on the week boundaries where locks stop counting.
+#[account]
+pub struct VotingEscrow {
+    pub bump: u8,
+    pub lock_manager: Pubkey,
+    /// Sum of amount × seconds until voting end over active locks, as of `last_checkpoint_time`.
+    pub bias: u128,
+    /// Sum of amount over active locks, how much `bias` decays per second.
+    pub slope: u64,
+    pub last_checkpoint_time: i64,
+    /// Slope removed at each week boundary, indexed by week number modulo `SLOPE_CHANGE_SLOTS`.
+    pub slope_changes: [u64; SLOPE_CHANGE_SLOTS],
+    /// Total voting power at the most recent week boundaries, for proposal snapshots.
+    pub history: [VotingPowerCheckpoint; VOTING_POWER_HISTORY_LEN],
+    /// Next `history` entry to overwrite.
+    pub history_index: u8,
+}
+
+impl VotingEscrow {
+    pub const LEN: usize = 8 + 1 + 32 + 16 + 8 + 8 + 8 * SLOPE_CHANGE_SLOTS + 16 * VOTING_POWER_HISTORY_LEN + 1;
+
+    pub fn initialize(&mut self, bump: u8, lock_manager: Pubkey, current_time: i64) -> Result<()> {
+        self.bump = bump;
+        self.lock_manager = lock_manager;
+        self.bias = 0;
+        self.slope = 0;
+        self.last_checkpoint_time = current_time;
+        self.slope_changes = [0; SLOPE_CHANGE_SLOTS];
+        self.history = [VotingPowerCheckpoint::default(); VOTING_POWER_HISTORY_LEN];
+        self.history_index = 0;
+        Ok(())
+    }
+
+    fn slot(week_time: i64) -> usize {
+        week_time.div_euclid(SECONDS_PER_WEEK).rem_euclid(SLOPE_CHANGE_SLOTS as i64) as usize
+    }
+
+    fn decay(&mut self, to_time: i64) {
+        let elapsed = to_time.saturating_sub(self.last_checkpoint_time).max(0) as u128;
+        self.bias = self.bias.saturating_sub(u128::from(self.slope) * elapsed);
+        self.last_checkpoint_time = to_time;
+    }
+
+    /// Brings the bias up to `current_time`, applying the slope changes of every week crossed.
+    pub fn checkpoint(&mut self, current_time: i64) -> Result<()> {
+        let mut week_time = round_down_to_week(self.last_checkpoint_time) + SECONDS_PER_WEEK;
+        let mut crossed_weeks = 0;
+        while week_time <= current_time && crossed_weeks < SLOPE_CHANGE_SLOTS {
+            self.decay(week_time);
+            let slot = Self::slot(week_time);
+            self.slope = self.slope.checked_sub(self.slope_changes[slot]).ok_or(XxusdError::MathOverflow)?;
+            self.slope_changes[slot] = 0;
+            self.record_history();
+            week_time += SECONDS_PER_WEEK;
+            crossed_weeks += 1;
+        }
+        self.decay(current_time.max(self.last_checkpoint_time));
+        Ok(())
+    }
+
+    fn record_history(&mut self) {
+        let index = usize::from(self.history_index) % VOTING_POWER_HISTORY_LEN;
+        self.history[index] = VotingPowerCheckpoint {
+            timestamp: self.last_checkpoint_time,
+            total_voting_power: self.get_total_voting_power(),
+        };
+        self.history_index = ((index + 1) % VOTING_POWER_HISTORY_LEN) as u8;
+    }
+
+    /// Total voting power as of `last_checkpoint_time`.
+    pub fn get_total_voting_power(&self) -> u64 {
+        (self.bias / MAX_VOTING_LOCK_PERIOD as u128).min(u128::from(u64::MAX)) as u64
+    }
+
+    fn add_lock(&mut self, amount: u64, voting_end_time: i64, current_time: i64) -> Result<()> {
+        if amount == 0 || voting_end_time <= current_time {
+            return Ok(());
+        }
+        let remaining_time = (voting_end_time - current_time) as u128;
+        self.bias = self
+            .bias
+            .checked_add(u128::from(amount) * remaining_time)
+            .ok_or(XxusdError::MathOverflow)?;
+        self.slope = self.slope.checked_add(amount).ok_or(XxusdError::MathOverflow)?;
+        let slot = Self::slot(voting_end_time);
+        self.slope_changes[slot] = self.slope_changes[slot].checked_add(amount).ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+
+    fn remove_lock(&mut self, amount: u64, voting_end_time: i64, current_time: i64) -> Result<()> {
+        // Locks whose voting end has passed were already dropped by `checkpoint`
+        if amount == 0 || voting_end_time <= current_time {
+            return Ok(());
+        }
+        let remaining_time = (voting_end_time - current_time) as u128;
+        self.bias = self.bias.saturating_sub(u128::from(amount) * remaining_time);
+        self.slope = self.slope.checked_sub(amount).ok_or(XxusdError::MathOverflow)?;
+        let slot = Self::slot(voting_end_time);
+        self.slope_changes[slot] = self.slope_changes[slot].checked_sub(amount).ok_or(XxusdError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Replaces a position's contribution after its unreleased amount or end time changed.
+    /// A fully released position no longer counts.
+    pub fn update_position_voting_power(&mut self, lock_position: &mut LockPosition, current_time: i64) -> Result<()> {
+        self.checkpoint(current_time)?;
+        self.remove_lock(lock_position.voting_amount, lock_position.voting_end_time, current_time)?;
+
+        let (voting_amount, voting_end_time) = if lock_position.is_fully_released() {
+            (0, 0)
+        } else {
+            (
+                lock_position.get_locked_amount()?.value(),
+                calculate_voting_end_time(lock_position.get_end_time(), current_time),
+            )
+        };
+        self.add_lock(voting_amount, voting_end_time, current_time)?;
+        lock_position.voting_amount = voting_amount;
+        lock_position.voting_end_time = voting_end_time;
+        Ok(())
+    }
+}
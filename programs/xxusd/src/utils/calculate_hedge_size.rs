@@ -0,0 +1,111 @@
+use anchor_lang::prelude::Result;
+
+use crate::adapters::perp::FUNDING_PRECISION;
+use crate::error::XxusdError;
+use crate::BPS_POWER;
+
+// Sizes are in collateral base units: a short of the collateral's own size cancels its price
+// exposure, so the hedge can be sized without a price. Prices only matter for USD reporting.
+
+/// Short size that hedges `hedge_ratio_bps` of the collateral's price exposure.
+pub fn calculate_target_hedge_size(collateral_amount: u64, hedge_ratio_bps: u16) -> Result<u64> {
+    let target = u128::from(collateral_amount)
+        .checked_mul(u128::from(hedge_ratio_bps))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(BPS_POWER);
+    u64::try_from(target).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Signed change to the perp position that brings the short back to its target, negative
+/// to short more. Zero while the distance to target stays within `tolerance_bps` of the
+/// collateral, so small moves do not trade.
+pub fn calculate_hedge_adjustment(
+    collateral_amount: u64,
+    short_size: u64,
+    hedge_ratio_bps: u16,
+    tolerance_bps: u16,
+) -> Result<i64> {
+    let target = calculate_target_hedge_size(collateral_amount, hedge_ratio_bps)?;
+    let missing_short = i128::from(target) - i128::from(short_size);
+
+    let tolerance = u128::from(collateral_amount)
+        .checked_mul(u128::from(tolerance_bps))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(BPS_POWER);
+    if missing_short.unsigned_abs() <= tolerance {
+        return Ok(0);
+    }
+
+    i64::try_from(-missing_short).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// USD value of `amount` base units of a token with `decimals`, at `price` in USD per token.
+pub fn calculate_usd_value(amount: u64, price: u64, decimals: u8) -> Result<u64> {
+    let value = u128::from(amount)
+        .checked_mul(u128::from(price))
+        .ok_or(XxusdError::MathOverflow)?
+        / 10u128.pow(u32::from(decimals));
+    u64::try_from(value).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Hedge ratio under a strategy's funding policy. While shorts pay more than `threshold_bps`
+/// per funding period, each further bps of funding cuts the ratio by `sensitivity_bps`, down
+/// to `min_hedge_ratio_bps`. `funding_rate` is scaled by `FUNDING_PRECISION`, positive when
+/// longs pay shorts.
+pub fn calculate_funding_adjusted_hedge_ratio(
+    funding_rate: i64,
+    threshold_bps: u16,
+    sensitivity_bps: u16,
+    min_hedge_ratio_bps: u16,
+) -> Result<u16> {
+    let full_ratio_bps = BPS_POWER as i128;
+    let short_funding_cost_bps = -i128::from(funding_rate) * full_ratio_bps / FUNDING_PRECISION;
+    let excess_cost_bps = short_funding_cost_bps - i128::from(threshold_bps);
+    if excess_cost_bps <= 0 {
+        return Ok(BPS_POWER as u16);
+    }
+
+    let hedge_ratio_bps = (full_ratio_bps - excess_cost_bps * i128::from(sensitivity_bps))
+        .max(i128::from(min_hedge_ratio_bps));
+    u16::try_from(hedge_ratio_bps).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_hedge_size() {
+        assert_eq!(calculate_target_hedge_size(1_000_000, 10_000).unwrap(), 1_000_000);
+        assert_eq!(calculate_target_hedge_size(1_000_000, 8_000).unwrap(), 800_000);
+    }
+
+    #[test]
+    fn test_adjustment_respects_tolerance() {
+        // 1% tolerance on 1_000_000 collateral is 10_000
+        assert_eq!(calculate_hedge_adjustment(1_000_000, 990_000, 10_000, 100).unwrap(), 0);
+        assert_eq!(calculate_hedge_adjustment(1_000_000, 989_999, 10_000, 100).unwrap(), -10_001);
+        // Over-hedged: buy back
+        assert_eq!(calculate_hedge_adjustment(1_000_000, 1_200_000, 10_000, 100).unwrap(), 200_000);
+        // Nothing to hedge: close the short
+        assert_eq!(calculate_hedge_adjustment(0, 5_000, 10_000, 100).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_funding_adjusted_hedge_ratio() {
+        let one_bps = (FUNDING_PRECISION / 10_000) as i64;
+        // Shorts receiving funding, or paying within the threshold: fully hedged
+        assert_eq!(calculate_funding_adjusted_hedge_ratio(5 * one_bps, 2, 1_000, 5_000).unwrap(), 10_000);
+        assert_eq!(calculate_funding_adjusted_hedge_ratio(-2 * one_bps, 2, 1_000, 5_000).unwrap(), 10_000);
+        // 3 bps over the threshold at 1_000 bps per bps of funding
+        assert_eq!(calculate_funding_adjusted_hedge_ratio(-5 * one_bps, 2, 1_000, 5_000).unwrap(), 7_000);
+        // Never below the floor
+        assert_eq!(calculate_funding_adjusted_hedge_ratio(-50 * one_bps, 2, 1_000, 5_000).unwrap(), 5_000);
+    }
+
+    #[test]
+    fn test_usd_value() {
+        // 1.5 SOL (9 decimals) at $150 (6 decimals)
+        assert_eq!(calculate_usd_value(1_500_000_000, 150_000_000, 9).unwrap(), 225_000_000);
+    }
+}
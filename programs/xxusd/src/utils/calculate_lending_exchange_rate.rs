@@ -0,0 +1,66 @@
+use anchor_lang::prelude::Result;
+use anchor_lang::require;
+
+use crate::error::XxusdError;
+
+/// Scale of a lending exchange rate, liquidity per collateral token.
+pub const EXCHANGE_RATE_PRECISION: u128 = 1_000_000_000_000;
+
+/// Liquidity backing one collateral token of a reserve, one to one while it is empty.
+pub fn calculate_exchange_rate(total_liquidity: u64, collateral_supply: u64) -> Result<u128> {
+    if collateral_supply == 0 {
+        return Ok(EXCHANGE_RATE_PRECISION);
+    }
+    let exchange_rate = u128::from(total_liquidity)
+        .checked_mul(EXCHANGE_RATE_PRECISION)
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(collateral_supply);
+    Ok(exchange_rate)
+}
+
+/// Liquidity `collateral_amount` can be redeemed for, rounded down.
+pub fn calculate_underlying_amount(collateral_amount: u64, exchange_rate: u128) -> Result<u64> {
+    let underlying_amount = u128::from(collateral_amount)
+        .checked_mul(exchange_rate)
+        .ok_or(XxusdError::MathOverflow)?
+        / EXCHANGE_RATE_PRECISION;
+    u64::try_from(underlying_amount).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Collateral to redeem for at least `liquidity_amount`, rounded up.
+pub fn calculate_collateral_to_redeem(liquidity_amount: u64, exchange_rate: u128) -> Result<u64> {
+    require!(exchange_rate > 0, XxusdError::InvalidExchangeRate);
+    let collateral_amount = u128::from(liquidity_amount)
+        .checked_mul(EXCHANGE_RATE_PRECISION)
+        .ok_or(XxusdError::MathOverflow)?
+        .div_ceil(exchange_rate);
+    u64::try_from(collateral_amount).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_reserve_is_one_to_one() {
+        let exchange_rate = calculate_exchange_rate(0, 0).unwrap();
+        assert_eq!(exchange_rate, EXCHANGE_RATE_PRECISION);
+        assert_eq!(calculate_underlying_amount(1_000, exchange_rate).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn test_interest_raises_underlying_amount() {
+        // 1_100 liquidity backing 1_000 collateral
+        let exchange_rate = calculate_exchange_rate(1_100, 1_000).unwrap();
+        assert_eq!(calculate_underlying_amount(500, exchange_rate).unwrap(), 550);
+    }
+
+    #[test]
+    fn test_collateral_to_redeem_rounds_up() {
+        // 3 liquidity per 2 collateral: 10 liquidity needs 6.67 collateral
+        let exchange_rate = calculate_exchange_rate(3, 2).unwrap();
+        let collateral_amount = calculate_collateral_to_redeem(10, exchange_rate).unwrap();
+        assert_eq!(collateral_amount, 7);
+        assert!(calculate_underlying_amount(collateral_amount, exchange_rate).unwrap() >= 10);
+    }
+}
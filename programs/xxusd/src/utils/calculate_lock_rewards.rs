@@ -0,0 +1,63 @@
+use anchor_lang::prelude::Result;
+use anchor_lang::require;
+
+use crate::error::XxusdError;
+use crate::BPS_POWER;
+
+/// Scale applied to `acc_reward_per_share` so small rewards over a large locked supply are not lost.
+pub const REWARD_PER_SHARE_PRECISION: u128 = 1_000_000_000_000;
+
+/// Reward weight of a locked amount, 10000 bps = 1x.
+pub fn calculate_weighted_amount(locked_amount: u64, reward_multiplier_bps: u16) -> Result<u128> {
+    let weighted_amount = u128::from(locked_amount)
+        .checked_mul(u128::from(reward_multiplier_bps))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(BPS_POWER);
+    Ok(weighted_amount)
+}
+
+/// How much `acc_reward_per_share` grows when `reward_amount` is shared over `total_weighted_amount`.
+pub fn calculate_reward_per_share_increase(reward_amount: u64, total_weighted_amount: u128) -> Result<u128> {
+    require!(total_weighted_amount > 0, XxusdError::InvalidAmount);
+    let increase = u128::from(reward_amount)
+        .checked_mul(REWARD_PER_SHARE_PRECISION)
+        .ok_or(XxusdError::MathOverflow)?
+        / total_weighted_amount;
+    Ok(increase)
+}
+
+/// Rewards earned by `weighted_amount` since `acc_reward_per_share` was zero.
+pub fn calculate_accrued_rewards(weighted_amount: u128, acc_reward_per_share: u128) -> Result<u128> {
+    let accrued_rewards = weighted_amount
+        .checked_mul(acc_reward_per_share)
+        .ok_or(XxusdError::MathOverflow)?
+        / REWARD_PER_SHARE_PRECISION;
+    Ok(accrued_rewards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_amount_applies_multiplier() {
+        assert_eq!(calculate_weighted_amount(1_000_000, 10_000).unwrap(), 1_000_000);
+        assert_eq!(calculate_weighted_amount(1_000_000, 15_000).unwrap(), 1_500_000);
+        assert_eq!(calculate_weighted_amount(0, 20_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_rewards_split_by_weight() {
+        let small = calculate_weighted_amount(1_000_000, 10_000).unwrap();
+        let large = calculate_weighted_amount(1_000_000, 30_000).unwrap();
+        let increase = calculate_reward_per_share_increase(400_000, small + large).unwrap();
+
+        assert_eq!(calculate_accrued_rewards(small, increase).unwrap(), 100_000);
+        assert_eq!(calculate_accrued_rewards(large, increase).unwrap(), 300_000);
+    }
+
+    #[test]
+    fn test_reward_per_share_requires_weight() {
+        assert!(calculate_reward_per_share_increase(1_000, 0).is_err());
+    }
+}
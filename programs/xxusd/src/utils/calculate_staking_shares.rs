@@ -0,0 +1,48 @@
+use anchor_lang::prelude::Result;
+
+use crate::error::XxusdError;
+
+// Both conversions add one virtual share and one virtual asset, so the first depositor cannot
+// inflate the share price to round later deposits down to zero shares. Both round down,
+// in favor of the vault.
+
+/// sxxUSD minted for depositing `assets` xxUSD.
+pub fn convert_to_shares(assets: u64, total_assets: u64, total_shares: u64) -> Result<u64> {
+    let shares = u128::from(assets)
+        .checked_mul(u128::from(total_shares) + 1)
+        .ok_or(XxusdError::MathOverflow)?
+        / (u128::from(total_assets) + 1);
+    u64::try_from(shares).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// xxUSD paid out for burning `shares` sxxUSD.
+pub fn convert_to_assets(shares: u64, total_assets: u64, total_shares: u64) -> Result<u64> {
+    let assets = u128::from(shares)
+        .checked_mul(u128::from(total_assets) + 1)
+        .ok_or(XxusdError::MathOverflow)?
+        / (u128::from(total_shares) + 1);
+    u64::try_from(assets).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_deposit_is_one_to_one() {
+        assert_eq!(convert_to_shares(1_000_000, 0, 0).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn test_yield_raises_share_price() {
+        // 1_000_000 shares backed by 1_100_000 xxUSD after 10% yield
+        assert_eq!(convert_to_assets(1_000_000, 1_100_000, 1_000_000).unwrap(), 1_099_999);
+        assert_eq!(convert_to_shares(1_100_000, 1_100_000, 1_000_000).unwrap(), 1_000_000);
+    }
+
+    #[test]
+    fn test_round_trip_never_gains() {
+        let shares = convert_to_shares(333_333, 1_234_567, 1_000_003).unwrap();
+        assert!(convert_to_assets(shares, 1_234_567 + 333_333, 1_000_003 + shares).unwrap() <= 333_333);
+    }
+}
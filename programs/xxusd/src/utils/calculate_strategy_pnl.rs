@@ -0,0 +1,96 @@
+use anchor_lang::prelude::Result;
+
+use crate::error::XxusdError;
+use crate::BPS_POWER;
+
+// Strategy PnL is kept in collateral base units, the unit of the strategy's principal, so
+// lending interest and perp gains add up without a price conversion.
+
+/// Profit of a `short_size` short opened at `entry_price` and marked at `mark_price`,
+/// converted to collateral base units at the mark price. Negative when the price went up.
+pub fn calculate_short_pnl(short_size: u64, entry_price: u64, mark_price: u64) -> Result<i64> {
+    if short_size == 0 || mark_price == 0 {
+        return Ok(0);
+    }
+    let pnl = i128::from(short_size)
+        .checked_mul(i128::from(entry_price) - i128::from(mark_price))
+        .ok_or(XxusdError::MathOverflow)?
+        / i128::from(mark_price);
+    i64::try_from(pnl).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Net asset value of a strategy minus its principal. The strategy holds `assets_amount` of
+/// collateral, in its vault or lent out, plus the PnL of its short net of funding paid.
+pub fn calculate_unrealized_pnl(
+    assets_amount: u64,
+    short_pnl: i64,
+    funding_paid: i64,
+    deposited_amount: u64,
+) -> Result<i64> {
+    let unrealized_pnl = i128::from(assets_amount) + i128::from(short_pnl)
+        - i128::from(funding_paid)
+        - i128::from(deposited_amount);
+    i64::try_from(unrealized_pnl).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Splits a lending withdrawal of `amount` into the principal it returns and the PnL it
+/// realizes: interest above principal, or a loss when the strategy's assets fell below its
+/// principal. Below principal, each withdrawal returns principal pro rata to the assets left.
+pub fn calculate_withdrawal_pnl(amount: u64, assets_amount: u64, deposited_amount: u64) -> Result<(u64, i64)> {
+    let principal_amount = if assets_amount >= deposited_amount {
+        amount.min(deposited_amount)
+    } else {
+        let principal_amount = u128::from(amount)
+            .checked_mul(u128::from(deposited_amount))
+            .ok_or(XxusdError::MathOverflow)?
+            / u128::from(assets_amount.max(1));
+        u64::try_from(principal_amount).unwrap_or(u64::MAX).min(deposited_amount)
+    };
+    let pnl = i64::try_from(i128::from(amount) - i128::from(principal_amount))
+        .map_err(|_| XxusdError::MathOverflow)?;
+    Ok((principal_amount, pnl))
+}
+
+/// `bps` of `amount`, rounded down.
+pub fn calculate_bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = u128::from(amount)
+        .checked_mul(u128::from(bps))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(BPS_POWER);
+    u64::try_from(share).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_pnl() {
+        // 1_000 short at $150, price drops to $100: $50_000 profit, 500 collateral at $100
+        assert_eq!(calculate_short_pnl(1_000, 150_000_000, 100_000_000).unwrap(), 500);
+        assert_eq!(calculate_short_pnl(1_000, 100_000_000, 125_000_000).unwrap(), -200);
+        assert_eq!(calculate_short_pnl(0, 100_000_000, 125_000_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_lending_interest_is_unrealized_pnl() {
+        assert_eq!(calculate_unrealized_pnl(1_050, 0, 0, 1_000).unwrap(), 50);
+    }
+
+    #[test]
+    fn test_withdrawal_pnl() {
+        // Interest on top of principal is realized once principal is returned
+        assert_eq!(calculate_withdrawal_pnl(600, 1_100, 1_000).unwrap(), (600, 0));
+        assert_eq!(calculate_withdrawal_pnl(1_100, 1_100, 1_000).unwrap(), (1_000, 100));
+        // 800 of assets left for 1_000 principal: losses are realized pro rata
+        assert_eq!(calculate_withdrawal_pnl(400, 800, 1_000).unwrap(), (500, -100));
+        assert_eq!(calculate_withdrawal_pnl(800, 800, 1_000).unwrap(), (1_000, -200));
+    }
+
+    #[test]
+    fn test_funding_reduces_unrealized_pnl() {
+        assert_eq!(calculate_unrealized_pnl(1_000, 200, 30, 1_000).unwrap(), 170);
+        // Received funding counts as profit
+        assert_eq!(calculate_unrealized_pnl(1_000, -200, -30, 1_000).unwrap(), -170);
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::Result;
+
+use crate::error::XxusdError;
+use crate::BPS_POWER;
+
+/// `part` as a share of `total`, in bps.
+pub fn calculate_share_bps(part: u64, total: u64) -> Result<u64> {
+    if total == 0 {
+        return Ok(0);
+    }
+    let share = u128::from(part)
+        .checked_mul(u128::from(BPS_POWER))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(total);
+    u64::try_from(share).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+/// Fall of the cumulative PnL from its high-water mark, in bps of the principal. Without
+/// principal any fall counts as a full drawdown.
+pub fn calculate_drawdown_bps(high_water_mark: i64, total_pnl: i64, deposited_amount: u64) -> Result<u64> {
+    let drawdown = i128::from(high_water_mark) - i128::from(total_pnl);
+    if drawdown <= 0 {
+        return Ok(0);
+    }
+    if deposited_amount == 0 {
+        return Ok(BPS_POWER);
+    }
+    let drawdown_bps = (drawdown as u128)
+        .checked_mul(u128::from(BPS_POWER))
+        .ok_or(XxusdError::MathOverflow)?
+        / u128::from(deposited_amount);
+    Ok(u64::try_from(drawdown_bps).unwrap_or(u64::MAX))
+}
+
+/// Short size over net asset value, in bps. Any short without positive equity is unbounded.
+pub fn calculate_leverage_bps(position_size: u64, net_asset_value: i64) -> Result<u64> {
+    if position_size == 0 {
+        return Ok(0);
+    }
+    if net_asset_value <= 0 {
+        return Ok(u64::MAX);
+    }
+    let leverage = u128::from(position_size)
+        .checked_mul(u128::from(BPS_POWER))
+        .ok_or(XxusdError::MathOverflow)?
+        / net_asset_value as u128;
+    Ok(u64::try_from(leverage).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share() {
+        assert_eq!(calculate_share_bps(250, 1_000).unwrap(), 2_500);
+        assert_eq!(calculate_share_bps(250, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_drawdown_from_high_water_mark() {
+        assert_eq!(calculate_drawdown_bps(100, 150, 1_000).unwrap(), 0);
+        // Down 150 from a 100 peak on 1_000 principal
+        assert_eq!(calculate_drawdown_bps(100, -50, 1_000).unwrap(), 1_500);
+        assert_eq!(calculate_drawdown_bps(0, -1, 0).unwrap(), BPS_POWER);
+    }
+
+    #[test]
+    fn test_leverage() {
+        assert_eq!(calculate_leverage_bps(3_000, 1_000).unwrap(), 30_000);
+        assert_eq!(calculate_leverage_bps(0, -1).unwrap(), 0);
+        assert_eq!(calculate_leverage_bps(1, 0).unwrap(), u64::MAX);
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::Result;
+
+use crate::error::XxusdError;
+
+/// Splits a redemption into the part the liquid depository serves now and the part queued
+/// against the term depository. The liquid part is capped by both the redeemable amount the
+/// liquid depository manages and the collateral it holds.
+pub fn calculate_redemption_split(
+    redeemable_amount: u64,
+    liquid_redeemable_amount: u128,
+    liquid_collateral_amount: u64,
+) -> (u64, u64) {
+    let liquid_amount = u128::from(redeemable_amount)
+        .min(liquid_redeemable_amount)
+        .min(u128::from(liquid_collateral_amount)) as u64;
+    (liquid_amount, redeemable_amount - liquid_amount)
+}
+
+/// Term depository redeemable amount under management after allocating `amount` to it.
+pub fn calculate_term_allocation(
+    term_redeemable_amount: u128,
+    amount: u64,
+    term_redeemable_amount_cap: u128,
+) -> Result<u128> {
+    let new_term_redeemable_amount = term_redeemable_amount
+        .checked_add(u128::from(amount))
+        .ok_or(XxusdError::Overflow)?;
+    if new_term_redeemable_amount > term_redeemable_amount_cap {
+        return Err(XxusdError::RedeemableAmountUnderManagementCapReached.into());
+    }
+    Ok(new_term_redeemable_amount)
+}
+
+/// Term depository redeemable amount under management and queued amount after a queued
+/// redemption of `redeemable_amount` is claimed.
+pub fn calculate_claimed_term_amounts(
+    term_redeemable_amount: u128,
+    queued_redeemable_amount: u128,
+    redeemable_amount: u64,
+) -> Result<(u128, u128)> {
+    let redeemable_amount = u128::from(redeemable_amount);
+    Ok((
+        term_redeemable_amount.checked_sub(redeemable_amount).ok_or(XxusdError::Overflow)?,
+        queued_redeemable_amount.checked_sub(redeemable_amount).ok_or(XxusdError::Overflow)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redemption_split() {
+        // Liquid depository covers everything
+        assert_eq!(calculate_redemption_split(1_000, 5_000, 5_000), (1_000, 0));
+        // Partial fill, the remainder is queued
+        assert_eq!(calculate_redemption_split(1_000, 600, 5_000), (600, 400));
+        assert_eq!(calculate_redemption_split(1_000, 5_000, 250), (250, 750));
+        // Liquid depository exhausted
+        assert_eq!(calculate_redemption_split(1_000, 0, 5_000), (0, 1_000));
+    }
+
+    #[test]
+    fn test_term_allocation() {
+        assert_eq!(calculate_term_allocation(4_000, 1_000, 5_000).unwrap(), 5_000);
+        assert!(calculate_term_allocation(4_000, 1_001, 5_000).is_err());
+    }
+
+    #[test]
+    fn test_claimed_term_amounts() {
+        assert_eq!(calculate_claimed_term_amounts(5_000, 1_500, 1_000).unwrap(), (4_000, 500));
+        // Cannot claim more than was queued
+        assert!(calculate_claimed_term_amounts(5_000, 500, 1_000).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use anchor_lang::prelude::Result;
+use anchor_lang::require;
+
+use crate::error::XxusdError;
+use crate::BPS_POWER;
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+pub struct VestingSchedule {
+    pub total_amount: u64,
+    pub start_time: i64,
+    /// Nothing vests before `start_time + cliff_period`.
+    pub cliff_period: i64,
+    /// Everything is vested at `start_time + vesting_period`.
+    pub vesting_period: i64,
+    /// Vesting only advances in whole steps of this many seconds.
+    pub release_interval: i64,
+}
+
+pub fn calculate_vested_amount(schedule: &VestingSchedule, current_time: i64) -> Result<u64> {
+    require!(schedule.vesting_period > 0, XxusdError::InvalidLockPeriod);
+    require!(schedule.release_interval > 0, XxusdError::InvalidLockPeriod);
+    require!(
+        schedule.cliff_period >= 0 && schedule.cliff_period <= schedule.vesting_period,
+        XxusdError::InvalidCliffPeriod
+    );
+
+    let elapsed = current_time
+        .checked_sub(schedule.start_time)
+        .ok_or(XxusdError::MathOverflow)?;
+
+    // Before the cliff nothing is vested
+    if elapsed < schedule.cliff_period || elapsed <= 0 {
+        return Ok(0);
+    }
+
+    // At the end of the period everything is vested, independently of rounding
+    if elapsed >= schedule.vesting_period {
+        return Ok(schedule.total_amount);
+    }
+
+    // In between, vest linearly over whole release intervals
+    let vested_elapsed = elapsed - elapsed % schedule.release_interval;
+    let vested_amount = u128::from(schedule.total_amount)
+        .checked_mul(vested_elapsed as u128)
+        .ok_or(XxusdError::MathOverflow)?
+        / schedule.vesting_period as u128;
+
+    Ok(u64::try_from(vested_amount).map_err(|_| XxusdError::MathOverflow)?)
+}
+
+pub fn calculate_releasable_amount(
+    schedule: &VestingSchedule,
+    released_amount: u64,
+    current_time: i64,
+) -> Result<u64> {
+    let vested_amount = calculate_vested_amount(schedule, current_time)?;
+    Ok(vested_amount.saturating_sub(released_amount))
+}
+
+/// Penalty for releasing `unvested_amount` early. It starts at `penalty_bps` of the unvested
+/// amount when the lock begins and decays linearly to zero at the end of the vesting period.
+pub fn calculate_early_release_penalty(
+    schedule: &VestingSchedule,
+    unvested_amount: u64,
+    penalty_bps: u16,
+    current_time: i64,
+) -> Result<u64> {
+    require!(schedule.vesting_period > 0, XxusdError::InvalidLockPeriod);
+    require!(u64::from(penalty_bps) <= BPS_POWER, XxusdError::InvalidBps);
+
+    let end_time = schedule
+        .start_time
+        .checked_add(schedule.vesting_period)
+        .ok_or(XxusdError::MathOverflow)?;
+    let remaining_time = end_time
+        .checked_sub(current_time)
+        .ok_or(XxusdError::MathOverflow)?
+        .clamp(0, schedule.vesting_period);
+
+    let penalty = u128::from(unvested_amount)
+        .checked_mul(u128::from(penalty_bps))
+        .and_then(|value| value.checked_mul(remaining_time as u128))
+        .ok_or(XxusdError::MathOverflow)?
+        / (u128::from(BPS_POWER) * schedule.vesting_period as u128);
+
+    Ok(u64::try_from(penalty).map_err(|_| XxusdError::MathOverflow)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(cliff_period: i64, release_interval: i64) -> VestingSchedule {
+        VestingSchedule {
+            total_amount: 1_000_000,
+            start_time: 1_000,
+            cliff_period,
+            vesting_period: 100 * SECONDS_PER_DAY,
+            release_interval,
+        }
+    }
+
+    #[test]
+    fn test_partial_claim_by_second() {
+        let schedule = schedule(0, 1);
+        let current_time = schedule.start_time + 25 * SECONDS_PER_DAY + 1;
+
+        let releasable = calculate_releasable_amount(&schedule, 0, current_time).unwrap();
+
+        // 25 days and one second out of 100 days
+        assert_eq!(releasable, 250_000);
+        assert_eq!(
+            calculate_vested_amount(&schedule, schedule.start_time + 8_640).unwrap(),
+            1_000
+        );
+    }
+
+    #[test]
+    fn test_partial_claim_by_day() {
+        let schedule = schedule(0, SECONDS_PER_DAY);
+
+        // Less than a day: nothing has vested yet
+        let current_time = schedule.start_time + SECONDS_PER_DAY - 1;
+        assert_eq!(calculate_releasable_amount(&schedule, 0, current_time).unwrap(), 0);
+
+        // Partial days are rounded down to whole days
+        let current_time = schedule.start_time + 10 * SECONDS_PER_DAY + SECONDS_PER_DAY / 2;
+        assert_eq!(calculate_releasable_amount(&schedule, 0, current_time).unwrap(), 100_000);
+    }
+
+    #[test]
+    fn test_cliff() {
+        let schedule = schedule(30 * SECONDS_PER_DAY, SECONDS_PER_DAY);
+
+        let before_cliff = schedule.start_time + 30 * SECONDS_PER_DAY - 1;
+        assert_eq!(calculate_vested_amount(&schedule, before_cliff).unwrap(), 0);
+
+        // Reaching the cliff unlocks everything vested since the start
+        let at_cliff = schedule.start_time + 30 * SECONDS_PER_DAY;
+        assert_eq!(calculate_vested_amount(&schedule, at_cliff).unwrap(), 300_000);
+    }
+
+    #[test]
+    fn test_repeated_claims() {
+        let schedule = schedule(0, SECONDS_PER_DAY);
+        let mut released_amount = 0;
+
+        for day in [10, 10, 35, 60] {
+            let current_time = schedule.start_time + day * SECONDS_PER_DAY;
+            let releasable =
+                calculate_releasable_amount(&schedule, released_amount, current_time).unwrap();
+            released_amount += releasable;
+            assert_eq!(released_amount, day as u64 * 10_000);
+        }
+
+        // Claiming again at the same time releases nothing more
+        let current_time = schedule.start_time + 60 * SECONDS_PER_DAY;
+        assert_eq!(
+            calculate_releasable_amount(&schedule, released_amount, current_time).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_final_claim_unlocks_everything() {
+        let schedule = VestingSchedule {
+            total_amount: 1_000_001,
+            start_time: 0,
+            cliff_period: 0,
+            vesting_period: 7 * SECONDS_PER_DAY,
+            release_interval: 1,
+        };
+
+        // Rounding leaves dust just before the end
+        let released_amount =
+            calculate_releasable_amount(&schedule, 0, schedule.vesting_period - 1).unwrap();
+        assert!(released_amount < schedule.total_amount);
+
+        // The final claim releases exactly the remainder
+        let end_time = schedule.start_time + schedule.vesting_period;
+        let releasable =
+            calculate_releasable_amount(&schedule, released_amount, end_time).unwrap();
+        assert_eq!(released_amount + releasable, schedule.total_amount);
+        assert_eq!(
+            calculate_releasable_amount(&schedule, schedule.total_amount, end_time + 1).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_before_start() {
+        let schedule = schedule(0, 1);
+        assert_eq!(calculate_vested_amount(&schedule, schedule.start_time - 1).unwrap(), 0);
+        assert_eq!(calculate_vested_amount(&schedule, schedule.start_time).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_early_release_penalty_decays() {
+        let schedule = schedule(0, 1);
+        let quarter = schedule.vesting_period / 4;
+
+        let at_start =
+            calculate_early_release_penalty(&schedule, 1_000_000, 5_000, schedule.start_time).unwrap();
+        assert_eq!(at_start, 500_000);
+
+        let at_quarter = calculate_early_release_penalty(
+            &schedule,
+            750_000,
+            5_000,
+            schedule.start_time + quarter,
+        )
+        .unwrap();
+        assert_eq!(at_quarter, 281_250);
+
+        let at_end = calculate_early_release_penalty(
+            &schedule,
+            1_000_000,
+            5_000,
+            schedule.start_time + schedule.vesting_period,
+        )
+        .unwrap();
+        assert_eq!(at_end, 0);
+    }
+
+    #[test]
+    fn test_invalid_cliff() {
+        let mut schedule = schedule(0, 1);
+        schedule.cliff_period = schedule.vesting_period + 1;
+        assert!(calculate_vested_amount(&schedule, schedule.start_time).is_err());
+    }
+}
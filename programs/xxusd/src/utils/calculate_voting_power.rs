@@ -0,0 +1,56 @@
+use anchor_lang::prelude::Result;
+
+use crate::error::XxusdError;
+use crate::utils::SECONDS_PER_DAY;
+
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+/// A lock with this much time left has voting power equal to its amount.
+pub const MAX_VOTING_LOCK_WEEKS: i64 = 104;
+pub const MAX_VOTING_LOCK_PERIOD: i64 = MAX_VOTING_LOCK_WEEKS * SECONDS_PER_WEEK;
+
+pub fn round_down_to_week(time: i64) -> i64 {
+    time.div_euclid(SECONDS_PER_WEEK) * SECONDS_PER_WEEK
+}
+
+/// Voting power stops at the last week boundary before the lock ends, and never more than
+/// `MAX_VOTING_LOCK_PERIOD` ahead, so the total supply only changes slope on week boundaries.
+pub fn calculate_voting_end_time(end_time: i64, current_time: i64) -> i64 {
+    round_down_to_week(end_time).min(round_down_to_week(current_time.saturating_add(MAX_VOTING_LOCK_PERIOD)))
+}
+
+/// amount × remaining time / max lock, decaying linearly to zero at `voting_end_time`.
+pub fn calculate_voting_power(amount: u64, voting_end_time: i64, current_time: i64) -> Result<u64> {
+    let remaining_time = voting_end_time.saturating_sub(current_time).max(0);
+    let voting_power = u128::from(amount)
+        .checked_mul(remaining_time as u128)
+        .ok_or(XxusdError::MathOverflow)?
+        / MAX_VOTING_LOCK_PERIOD as u128;
+    u64::try_from(voting_power).map_err(|_| XxusdError::MathOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_000 * SECONDS_PER_WEEK;
+
+    #[test]
+    fn test_voting_power_decays_linearly() {
+        let voting_end_time = NOW + MAX_VOTING_LOCK_PERIOD;
+        assert_eq!(calculate_voting_power(1_000_000, voting_end_time, NOW).unwrap(), 1_000_000);
+        assert_eq!(calculate_voting_power(1_000_000, voting_end_time, NOW + MAX_VOTING_LOCK_PERIOD / 4).unwrap(), 750_000);
+        assert_eq!(calculate_voting_power(1_000_000, voting_end_time, voting_end_time).unwrap(), 0);
+        assert_eq!(calculate_voting_power(1_000_000, voting_end_time, voting_end_time + 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_voting_end_time_rounds_to_week() {
+        assert_eq!(calculate_voting_end_time(NOW + 10 * SECONDS_PER_WEEK + 5, NOW + 3), NOW + 10 * SECONDS_PER_WEEK);
+    }
+
+    #[test]
+    fn test_voting_end_time_is_capped() {
+        let end_time = NOW + 4 * MAX_VOTING_LOCK_PERIOD;
+        assert_eq!(calculate_voting_end_time(end_time, NOW), NOW + MAX_VOTING_LOCK_PERIOD);
+    }
+}
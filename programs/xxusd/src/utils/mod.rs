@@ -1,5 +1,23 @@
 pub mod maths;
 pub mod validate_collateral_amount;
+pub mod calculate_vested_amount;
+pub mod calculate_lock_rewards;
+pub mod calculate_staking_shares;
+pub mod calculate_voting_power;
+pub mod calculate_hedge_size;
+pub mod calculate_lending_exchange_rate;
+pub mod calculate_strategy_pnl;
+pub mod calculate_strategy_risk;
+pub mod calculate_term_redemption;
 
 pub use maths::*;
-pub use validate_collateral_amount::*;
\ No newline at end of file
+pub use validate_collateral_amount::*;
+pub use calculate_vested_amount::*;
+pub use calculate_lock_rewards::*;
+pub use calculate_staking_shares::*;
+pub use calculate_voting_power::*;
+pub use calculate_hedge_size::*;
+pub use calculate_lending_exchange_rate::*;
+pub use calculate_strategy_pnl::*;
+pub use calculate_strategy_risk::*;
+pub use calculate_term_redemption::*;
\ No newline at end of file
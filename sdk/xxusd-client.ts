@@ -1,5 +1,14 @@
-import { Connection, PublicKey, Transaction, TransactionInstruction } from '@solana/web3.js';
+import {
+  Connection,
+  PublicKey,
+  Signer,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from '@solana/web3.js';
 import { Program, Provider, BN } from '@project-serum/anchor';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from '@solana/spl-token';
 import idl from '../target/idl/xxusd.json';
 
 export class XxusdClient {
@@ -61,37 +70,153 @@ export class XxusdClient {
     return tx;
   }
 
-  async lockXxusd(user: PublicKey, amount: number, lockPeriod: number): Promise<string> {
+  findLockPositionAddress(owner: PublicKey, nonce: number): PublicKey {
+    const [lockPositionPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_position'), owner.toBuffer(), new BN(nonce).toArrayLike(Buffer, 'le', 8)],
+      this.programId
+    );
+    return lockPositionPda;
+  }
+
+  async getLockPosition(owner: PublicKey, nonce: number): Promise<any> {
+    return this.program.account['lockPosition'].fetchNullable(this.findLockPositionAddress(owner, nonce));
+  }
+
+  async initializeLockTier(
+    authority: PublicKey,
+    tierId: number,
+    lockPeriod: number,
+    rewardMultiplierBps: number
+  ): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+    const [lockManagerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_manager')],
+      this.programId
+    );
+    const [lockTierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_tier'), Buffer.from([tierId])],
+      this.programId
+    );
+
+    const tx = await this.program.methods
+      .initializeLockTier({ tierId, lockPeriod: new BN(lockPeriod), rewardMultiplierBps })
+      .accounts({
+        authority,
+        controller: controllerPda,
+        lockManager: lockManagerPda,
+        lockTier: lockTierPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Locks `amount` xxUSD in a new position seeded by `user` and `nonce`. The position's
+  // receipt goes to `beneficiary`, who controls the position.
+  async lockXxusd(
+    user: PublicKey,
+    nonce: number,
+    amount: number,
+    tierId: number,
+    beneficiary: PublicKey = user,
+    cliffPeriod = 0,
+    releaseInterval: object = { second: {} }
+  ): Promise<string> {
     const [controllerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('controller')],
       this.programId
     );
+    const [lockManagerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_manager')],
+      this.programId
+    );
+    const [votingEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('voting_escrow')],
+      this.programId
+    );
+    const [lockTierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_tier'), Buffer.from([tierId])],
+      this.programId
+    );
+    const lockPositionPda = this.findLockPositionAddress(user, nonce);
+    const [receiptMintPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_receipt'), lockPositionPda.toBuffer()],
+      this.programId
+    );
+    const controller: any = await this.program.account['controller'].fetch(controllerPda);
 
     const tx = await this.program.methods
-      .lockXxusd(new BN(amount), new BN(lockPeriod))
+      .lockXxusd({
+        nonce: new BN(nonce),
+        amount: new BN(amount),
+        tierId,
+        cliffPeriod: new BN(cliffPeriod),
+        releaseInterval,
+      })
       .accounts({
         user,
+        beneficiary,
         controller: controllerPda,
-        // Add other necessary accounts here
+        lockManager: lockManagerPda,
+        votingEscrow: votingEscrowPda,
+        lockTier: lockTierPda,
+        lockPosition: lockPositionPda,
+        userXxusd: getAssociatedTokenAddressSync(controller.xxusdMint, user),
+        lockVault: getAssociatedTokenAddressSync(controller.xxusdMint, lockManagerPda, true),
+        receiptMint: receiptMintPda,
+        beneficiaryReceipt: getAssociatedTokenAddressSync(receiptMintPda, beneficiary),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .rpc();
 
     return tx;
   }
 
-  async releaseXxusd(user: PublicKey): Promise<string> {
+  // Releases what has vested of the position created by `owner` with `nonce`. `user` must
+  // hold the position's receipt; the xxUSD goes to the receipt holder.
+  async releaseXxusd(user: PublicKey, owner: PublicKey, nonce: number, signers: Signer[] = []): Promise<string> {
     const [controllerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('controller')],
       this.programId
     );
+    const [lockManagerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_manager')],
+      this.programId
+    );
+    const [votingEscrowPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('voting_escrow')],
+      this.programId
+    );
+    const lockPositionPda = this.findLockPositionAddress(owner, nonce);
+    const controller: any = await this.program.account['controller'].fetch(controllerPda);
+    const lockManager: any = await this.program.account['lockManager'].fetch(lockManagerPda);
+    const lockPosition: any = await this.program.account['lockPosition'].fetch(lockPositionPda);
 
     const tx = await this.program.methods
       .releaseXxusd()
       .accounts({
         user,
         controller: controllerPda,
-        // Add other necessary accounts here
+        lockManager: lockManagerPda,
+        votingEscrow: votingEscrowPda,
+        lockPosition: lockPositionPda,
+        receiptMint: lockPosition.receiptMint,
+        userReceipt: getAssociatedTokenAddressSync(lockPosition.receiptMint, user),
+        userXxusd: getAssociatedTokenAddressSync(controller.xxusdMint, user),
+        lockVault: getAssociatedTokenAddressSync(controller.xxusdMint, lockManagerPda, true),
+        lockRewardVault: lockManager.lockRewardVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
+      .signers(signers)
       .rpc();
 
     return tx;
@@ -131,54 +256,329 @@ export class XxusdClient {
     return price;
   }
 
-  async depositToHedgingStrategy(authority: PublicKey, amount: number): Promise<string> {
+  findHedgingStrategyAddress(strategyId: number): PublicKey {
+    const [hedgingStrategyPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('hedging_strategy'), Buffer.from([strategyId])],
+      this.programId
+    );
+    return hedgingStrategyPda;
+  }
+
+  findKaminoDepositoryAddress(collateralMint: PublicKey): PublicKey {
+    const [kaminoDepositoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('kamino_depository'), collateralMint.toBuffer()],
+      this.programId
+    );
+    return kaminoDepositoryPda;
+  }
+
+  findInsuranceFundAddress(collateralMint: PublicKey): PublicKey {
+    const [insuranceFundPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('insurance_fund'), collateralMint.toBuffer()],
+      this.programId
+    );
+    return insuranceFundPda;
+  }
+
+  findHedgingAuditLogAddress(strategyId: number): PublicKey {
+    const [hedgingAuditLogPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('hedging_audit_log'), this.findHedgingStrategyAddress(strategyId).toBuffer()],
+      this.programId
+    );
+    return hedgingAuditLogPda;
+  }
+
+  async getHedgingStrategy(strategyId: number): Promise<any> {
+    return this.program.account['hedgingStrategy'].fetchNullable(this.findHedgingStrategyAddress(strategyId));
+  }
+
+  // `strategyType` is `{ lending: {} }` or `{ perpShort: {} }`. The venue is the lending
+  // program and reserve of a Lending strategy, the perp program and market of a PerpShort one.
+  async initializeHedgingStrategy(
+    authority: PublicKey,
+    collateralMint: PublicKey,
+    strategyId: number,
+    strategyType: object,
+    venueProgram: PublicKey,
+    venueMarket: PublicKey,
+    maxAllocation: number,
+    targetAllocationBps = 10_000,
+    deltaToleranceBps = 100
+  ): Promise<string> {
     const [controllerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('controller')],
       this.programId
     );
+    const hedgingStrategyPda = this.findHedgingStrategyAddress(strategyId);
+    // Only a PerpShort strategy claims the collateral mint's perp hedge
+    const perpHedgePda = 'perpShort' in strategyType
+      ? PublicKey.findProgramAddressSync([Buffer.from('perp_hedge'), collateralMint.toBuffer()], this.programId)[0]
+      : null;
 
     const tx = await this.program.methods
-      .depositToHedgingStrategy(new BN(amount))
+      .initializeHedgingStrategy({
+        strategyId,
+        strategyType,
+        targetAllocationBps,
+        maxAllocation: new BN(maxAllocation),
+        venueProgram,
+        venueMarket,
+        deltaToleranceBps,
+        maxCollateralShareBps: 0,
+        maxDrawdownBps: 0,
+        maxLeverageBps: 0,
+      })
       .accounts({
         authority,
         controller: controllerPda,
-        // Add other necessary accounts here
+        collateralMint,
+        kaminoDepository: this.findKaminoDepositoryAddress(collateralMint),
+        hedgingStrategy: hedgingStrategyPda,
+        hedgingStrategyVault: getAssociatedTokenAddressSync(collateralMint, hedgingStrategyPda, true),
+        perpHedge: perpHedgePda,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .rpc();
 
     return tx;
   }
 
-  async withdrawFromHedgingStrategy(authority: PublicKey, amount: number): Promise<string> {
+  async initializeInsuranceFund(authority: PublicKey, collateralMint: PublicKey, yieldShareBps: number): Promise<string> {
     const [controllerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('controller')],
       this.programId
     );
+    const insuranceFundPda = this.findInsuranceFundAddress(collateralMint);
 
     const tx = await this.program.methods
-      .withdrawFromHedgingStrategy(new BN(amount))
+      .initializeInsuranceFund(yieldShareBps)
       .accounts({
         authority,
         controller: controllerPda,
-        // Add other necessary accounts here
+        collateralMint,
+        insuranceFund: insuranceFundPda,
+        insuranceFundVault: getAssociatedTokenAddressSync(collateralMint, insuranceFundPda, true),
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
       })
       .rpc();
 
     return tx;
   }
 
-  async updateHedgingStrategyParameters(authority: PublicKey, collateralRatio: number): Promise<string> {
+  async initializeHedgingAuditLog(authority: PublicKey, strategyId: number): Promise<string> {
     const [controllerPda] = PublicKey.findProgramAddressSync(
       [Buffer.from('controller')],
       this.programId
     );
 
     const tx = await this.program.methods
-      .updateHedgingStrategyParameters(new BN(collateralRatio))
+      .initializeHedgingAuditLog()
       .accounts({
         authority,
         controller: controllerPda,
-        // Add other necessary accounts here
+        hedgingStrategy: this.findHedgingStrategyAddress(strategyId),
+        hedgingAuditLog: this.findHedgingAuditLogAddress(strategyId),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Moves `amount` collateral between the depository and the strategy. A Lending strategy
+  // also lends it to, or redeems it from, its reserve: pass the reserve's liquidity supply
+  // and collateral mint.
+  async manageHedgingStrategy(
+    authority: PublicKey,
+    strategyId: number,
+    amount: number,
+    isDeposit: boolean,
+    lendingReserve: { liquiditySupply: PublicKey; collateralMint: PublicKey } | null = null
+  ): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+    const hedgingStrategyPda = this.findHedgingStrategyAddress(strategyId);
+    const hedgingStrategy: any = await this.program.account['hedgingStrategy'].fetch(hedgingStrategyPda);
+    const collateralMint: PublicKey = hedgingStrategy.collateralMint;
+    const kaminoDepositoryPda = this.findKaminoDepositoryAddress(collateralMint);
+    const insuranceFundPda = this.findInsuranceFundAddress(collateralMint);
+
+    const tx = await this.program.methods
+      .manageHedgingStrategy(new BN(amount), isDeposit)
+      .accounts({
+        authority,
+        controller: controllerPda,
+        hedgingStrategy: hedgingStrategyPda,
+        collateralMint,
+        kaminoDepository: kaminoDepositoryPda,
+        depositoryCollateral: getAssociatedTokenAddressSync(collateralMint, kaminoDepositoryPda, true),
+        hedgingStrategyVault: getAssociatedTokenAddressSync(collateralMint, hedgingStrategyPda, true),
+        insuranceFund: insuranceFundPda,
+        insuranceFundVault: getAssociatedTokenAddressSync(collateralMint, insuranceFundPda, true),
+        hedgingAuditLog: this.findHedgingAuditLogAddress(strategyId),
+        lendingProgram: lendingReserve ? hedgingStrategy.venueProgram : null,
+        lendingReserve: lendingReserve ? hedgingStrategy.venueMarket : null,
+        reserveLiquiditySupply: lendingReserve ? lendingReserve.liquiditySupply : null,
+        reserveCollateralMint: lendingReserve ? lendingReserve.collateralMint : null,
+        hedgingStrategyCollateral: lendingReserve
+          ? getAssociatedTokenAddressSync(lendingReserve.collateralMint, hedgingStrategyPda, true)
+          : null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  findSwapProgramAddress(ammProgram: PublicKey): PublicKey {
+    const [swapProgramPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('swap_program'), ammProgram.toBuffer()],
+      this.programId
+    );
+    return swapProgramPda;
+  }
+
+  async registerSwapProgram(authority: PublicKey, ammProgram: PublicKey): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+
+    const tx = await this.program.methods
+      .registerSwapProgram(ammProgram)
+      .accounts({
+        authority,
+        controller: controllerPda,
+        swapProgram: this.findSwapProgramAddress(ammProgram),
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Swaps the strategy's `sourceMint` vault into its collateral vault through `swapInstruction`,
+  // an instruction of a whitelisted AMM built with the strategy PDA as the swap's authority.
+  async swapAssets(
+    authority: PublicKey,
+    strategyId: number,
+    sourceMint: PublicKey,
+    destinationMint: PublicKey,
+    amountIn: number,
+    minAmountOut: number,
+    swapInstruction: TransactionInstruction
+  ): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+    const hedgingStrategyPda = this.findHedgingStrategyAddress(strategyId);
+
+    const tx = await this.program.methods
+      .swapAssets(new BN(amountIn), new BN(minAmountOut), swapInstruction.data)
+      .accounts({
+        authority,
+        controller: controllerPda,
+        hedgingStrategy: hedgingStrategyPda,
+        swapProgram: this.findSwapProgramAddress(swapInstruction.programId),
+        hedgingAuditLog: this.findHedgingAuditLogAddress(strategyId),
+        ammProgram: swapInstruction.programId,
+        sourceMint,
+        destinationMint,
+        sourceAccount: getAssociatedTokenAddressSync(sourceMint, hedgingStrategyPda, true),
+        destinationAccount: getAssociatedTokenAddressSync(destinationMint, hedgingStrategyPda, true),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      })
+      // The program signs for the strategy PDA, the transaction cannot
+      .remainingAccounts(swapInstruction.keys.map(key => ({ ...key, isSigner: false })))
+      .rpc();
+
+    return tx;
+  }
+  // Only the given fields are changed, see `EditHedgingStrategyParams`.
+  async editHedgingStrategy(authority: PublicKey, strategyId: number, params: object): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+
+    const tx = await this.program.methods
+      .editHedgingStrategy({
+        targetAllocationBps: null,
+        maxAllocation: null,
+        isEnabled: null,
+        deltaToleranceBps: null,
+        maxCollateralShareBps: null,
+        maxDrawdownBps: null,
+        maxLeverageBps: null,
+        isWithdrawOnly: null,
+        fundingRateThresholdBps: null,
+        fundingSensitivityBps: null,
+        minHedgeRatioBps: null,
+        maxFundingRateAge: null,
+        ...params,
+      })
+      .accounts({
+        authority,
+        controller: controllerPda,
+        hedgingStrategy: this.findHedgingStrategyAddress(strategyId),
+      })
+      .rpc();
+
+    return tx;
+  }
+
+  // Resizes a PerpShort strategy's short. `perpPosition` is the strategy's position on its
+  // market and `perpMarginVault` the market's vault realized PnL settles through.
+  async rebalanceHedge(
+    authority: PublicKey,
+    strategyId: number,
+    perpPosition: PublicKey,
+    perpMarginVault: PublicKey
+  ): Promise<string> {
+    const [controllerPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('controller')],
+      this.programId
+    );
+    const hedgingStrategyPda = this.findHedgingStrategyAddress(strategyId);
+    const hedgingStrategy: any = await this.program.account['hedgingStrategy'].fetch(hedgingStrategyPda);
+    const collateralMint: PublicKey = hedgingStrategy.collateralMint;
+    const kaminoDepositoryPda = this.findKaminoDepositoryAddress(collateralMint);
+    const insuranceFundPda = this.findInsuranceFundAddress(collateralMint);
+    const [perpHedgePda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('perp_hedge'), collateralMint.toBuffer()],
+      this.programId
+    );
+
+    const tx = await this.program.methods
+      .rebalanceHedge()
+      .accounts({
+        authority,
+        controller: controllerPda,
+        hedgingStrategy: hedgingStrategyPda,
+        collateralMint,
+        kaminoDepository: kaminoDepositoryPda,
+        perpHedge: perpHedgePda,
+        depositoryCollateral: getAssociatedTokenAddressSync(collateralMint, kaminoDepositoryPda, true),
+        hedgingStrategyVault: getAssociatedTokenAddressSync(collateralMint, hedgingStrategyPda, true),
+        insuranceFund: insuranceFundPda,
+        insuranceFundVault: getAssociatedTokenAddressSync(collateralMint, insuranceFundPda, true),
+        perpProgram: hedgingStrategy.venueProgram,
+        perpMarket: hedgingStrategy.venueMarket,
+        perpPosition,
+        perpMarginVault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       })
       .rpc();
 
@@ -1,35 +1,65 @@
 import * as anchor from '@project-serum/anchor';
-import { PublicKey } from '@solana/web3.js';
+import { Keypair } from '@solana/web3.js';
 import { expect } from 'chai';
-import { authority, user, xxusdProgramId, xxusdClient } from './constants';
+import { authority, user, xxusdClient } from './constants';
 import { getConnection } from './connection';
 import { uiToNative } from './utils';
 
 describe('Lock and Release xxUSD', () => {
   const connection = getConnection();
+  const tierId = 0;
+  const lockPeriod = 10; // seconds, so the test can wait for the whole lock
+  // Positions are seeded by user + nonce, a fresh nonce per run avoids reusing a position
+  const nonce = Date.now();
+  const lockAmount = uiToNative(5, 6).toNumber(); // Lock 5 xxUSD
 
   before(async () => {
     // Mint some xxUSD for the user to lock
     await xxusdClient.mint(user.publicKey, uiToNative(12, 9).toNumber(), uiToNative(10, 6).toNumber());
+
+    const [lockTierPda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('lock_tier'), Buffer.from([tierId])],
+      xxusdClient.programId
+    );
+    if ((await connection.getAccountInfo(lockTierPda)) === null) {
+      await xxusdClient.initializeLockTier(authority.publicKey, tierId, lockPeriod, 10_000);
+    }
   });
 
-  it('should lock xxUSD', async () => {
-    const lockAmount = uiToNative(5, 6).toNumber(); // Lock 5 xxUSD
-    const lockPeriod = 7 * 24 * 60 * 60; // 7 days in seconds
+  it('should lock xxUSD in a new position', async () => {
+    await xxusdClient.lockXxusd(user.publicKey, nonce, lockAmount, tierId);
+
+    const lockPosition = await xxusdClient.getLockPosition(user.publicKey, nonce);
+    expect(lockPosition.owner.toBase58()).to.equal(user.publicKey.toBase58());
+    expect(lockPosition.nonce.toNumber()).to.equal(nonce);
+    expect(lockPosition.amount.toNumber()).to.equal(lockAmount);
+    expect(lockPosition.releasedAmount.toNumber()).to.equal(0);
+  });
 
-    await xxusdClient.lockXxusd(user.publicKey, lockAmount, lockPeriod);
+  it('should not let another wallet release the position', async () => {
+    const stranger = Keypair.generate();
 
-    // TODO: Add checks for locked xxUSD balance
-    // This will require implementing a function to get locked xxUSD balance
+    let failed = false;
+    try {
+      await xxusdClient.releaseXxusd(stranger.publicKey, user.publicKey, nonce, [stranger]);
+    } catch (e) {
+      failed = true;
+    }
+    expect(failed).to.equal(true);
   });
 
-  it('should release xxUSD after lock period', async () => {
-    // Fast-forward time (this is a simplified approach, you might need to use a different method in your actual testing environment)
-    await new Promise(resolve => setTimeout(resolve, 7 * 24 * 60 * 60 * 1000));
+  it('should release the position to its owner after the lock period', async () => {
+    const lockTier: any = await xxusdClient.program.account['lockTier'].fetch(
+      anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from('lock_tier'), Buffer.from([tierId])],
+        xxusdClient.programId
+      )[0]
+    );
+    await new Promise(resolve => setTimeout(resolve, (lockTier.lockPeriod.toNumber() + 2) * 1000));
 
-    await xxusdClient.releaseXxusd(user.publicKey);
+    await xxusdClient.releaseXxusd(user.publicKey, user.publicKey, nonce);
 
-    // TODO: Add checks for released xxUSD balance
-    // This will require implementing a function to get xxUSD balance
+    const lockPosition = await xxusdClient.getLockPosition(user.publicKey, nonce);
+    expect(lockPosition.releasedAmount.toNumber()).to.equal(lockAmount);
   });
-});
\ No newline at end of file
+});
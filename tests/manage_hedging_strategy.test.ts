@@ -1,37 +1,63 @@
-import * as anchor from '@project-serum/anchor';
-import { PublicKey } from '@solana/web3.js';
 import { expect } from 'chai';
-import { authority, xxusdProgramId, xxusdClient } from './constants';
+import { getAssociatedTokenAddressSync } from '@solana/spl-token';
+import { authority, xxusdClient, JUPSOL_DEVNET, JUPSOL_DEVNET_DECIMALS } from './constants';
 import { getConnection } from './connection';
+import { findReserveAddresses, initializeLendingStrategyIfMissing } from './mocks';
 import { uiToNative } from './utils';
 
 describe('Manage Hedging Strategy', () => {
   const connection = getConnection();
+  const strategyId = 1;
+  const reserve = findReserveAddresses(JUPSOL_DEVNET);
+  const hedgingStrategyPda = xxusdClient.findHedgingStrategyAddress(strategyId);
+  const hedgingStrategyCollateral = getAssociatedTokenAddressSync(reserve.collateralMint, hedgingStrategyPda, true);
+  const depositAmount = uiToNative(1, JUPSOL_DEVNET_DECIMALS).toNumber();
+
+  before(async () => {
+    // A Lending strategy on the mock lending reserve of jupSOL
+    await initializeLendingStrategyIfMissing(strategyId, JUPSOL_DEVNET, uiToNative(100, JUPSOL_DEVNET_DECIMALS).toNumber());
+  });
 
-  it('should deposit to hedging strategy', async () => {
-    const depositAmount = uiToNative(1000, 6).toNumber(); // 1000 xxUSD
+  it('should deposit to a lending strategy and lend the collateral', async () => {
+    const before = await xxusdClient.getHedgingStrategy(strategyId);
+    const supplyBefore = await connection.getTokenAccountBalance(reserve.liquiditySupply);
 
-    await xxusdClient.depositToHedgingStrategy(authority.publicKey, depositAmount);
+    await xxusdClient.manageHedgingStrategy(authority.publicKey, strategyId, depositAmount, true, reserve);
 
-    // TODO: Add checks for hedging strategy balance
-    // This will require implementing a function to get hedging strategy balance
+    const hedgingStrategy = await xxusdClient.getHedgingStrategy(strategyId);
+    expect(hedgingStrategy.depositedAmount.sub(before.depositedAmount).toNumber()).to.equal(depositAmount);
+    const supply = await connection.getTokenAccountBalance(reserve.liquiditySupply);
+    expect(Number(supply.value.amount) - Number(supplyBefore.value.amount)).to.equal(depositAmount);
+    const collateral = await connection.getTokenAccountBalance(hedgingStrategyCollateral);
+    expect(Number(collateral.value.amount)).to.be.greaterThan(0);
   });
 
-  it('should withdraw from hedging strategy', async () => {
-    const withdrawAmount = uiToNative(500, 6).toNumber(); // 500 xxUSD
+  it('should redeem from the reserve to withdraw from a lending strategy', async () => {
+    const before = await xxusdClient.getHedgingStrategy(strategyId);
+    const collateralBefore = await connection.getTokenAccountBalance(hedgingStrategyCollateral);
 
-    await xxusdClient.withdrawFromHedgingStrategy(authority.publicKey, withdrawAmount);
+    await xxusdClient.manageHedgingStrategy(authority.publicKey, strategyId, depositAmount / 2, false, reserve);
 
-    // TODO: Add checks for hedging strategy balance
-    // This will require implementing a function to get hedging strategy balance
+    const hedgingStrategy = await xxusdClient.getHedgingStrategy(strategyId);
+    expect(before.depositedAmount.sub(hedgingStrategy.depositedAmount).toNumber()).to.equal(depositAmount / 2);
+    const collateral = await connection.getTokenAccountBalance(hedgingStrategyCollateral);
+    expect(Number(collateral.value.amount)).to.be.lessThan(Number(collateralBefore.value.amount));
   });
 
-  it('should update hedging strategy parameters', async () => {
-    const newCollateralRatio = 120; // 120%
-
-    await xxusdClient.updateHedgingStrategyParameters(authority.publicKey, newCollateralRatio);
+  it('should not deposit to a lending strategy without its reserve', async () => {
+    let failed = false;
+    try {
+      await xxusdClient.manageHedgingStrategy(authority.publicKey, strategyId, depositAmount, true);
+    } catch (e) {
+      failed = true;
+    }
+    expect(failed).to.equal(true);
+  });
 
-    // TODO: Add checks for updated hedging strategy parameters
-    // This will require implementing a function to get hedging strategy parameters
+  it('should record every operation in the audit log', async () => {
+    const hedgingAuditLog: any = await xxusdClient.program.account['hedgingAuditLog'].fetch(
+      xxusdClient.findHedgingAuditLogAddress(strategyId)
+    );
+    expect(hedgingAuditLog.operationCount.toNumber()).to.be.greaterThanOrEqual(2);
   });
-});
\ No newline at end of file
+});
@@ -0,0 +1,246 @@
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
+import * as anchor from '@project-serum/anchor';
+import { TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from '@solana/spl-token';
+import mockAmmIdl from '../target/idl/mock_amm.json';
+import mockLendingIdl from '../target/idl/mock_lending.json';
+import mockPerpIdl from '../target/idl/mock_perp.json';
+import { authority, xxusdClient } from './constants';
+import { getConnection } from './connection';
+
+// Local test venues deployed with the workspace, see programs/mock-*
+
+export const mockAmmProgram = new anchor.Program(
+  mockAmmIdl as any,
+  new PublicKey((mockAmmIdl as any).metadata.address),
+  xxusdClient.provider
+);
+
+export const mockLendingProgram = new anchor.Program(
+  mockLendingIdl as any,
+  new PublicKey((mockLendingIdl as any).metadata.address),
+  xxusdClient.provider
+);
+
+export const mockPerpProgram = new anchor.Program(
+  mockPerpIdl as any,
+  new PublicKey((mockPerpIdl as any).metadata.address),
+  xxusdClient.provider
+);
+
+export function findReserveAddresses(liquidityMint: PublicKey): {
+  reserve: PublicKey;
+  liquiditySupply: PublicKey;
+  collateralMint: PublicKey;
+} {
+  const programId = mockLendingProgram.programId;
+  const [reserve] = PublicKey.findProgramAddressSync([Buffer.from('reserve'), liquidityMint.toBuffer()], programId);
+  const [liquiditySupply] = PublicKey.findProgramAddressSync(
+    [Buffer.from('reserve_liquidity'), reserve.toBuffer()],
+    programId
+  );
+  const [collateralMint] = PublicKey.findProgramAddressSync(
+    [Buffer.from('reserve_collateral_mint'), reserve.toBuffer()],
+    programId
+  );
+  return { reserve, liquiditySupply, collateralMint };
+}
+
+export async function initializeReserveIfMissing(liquidityMint: PublicKey): Promise<void> {
+  const { reserve, liquiditySupply, collateralMint } = findReserveAddresses(liquidityMint);
+  if ((await getConnection().getAccountInfo(reserve)) !== null) {
+    return;
+  }
+  await mockLendingProgram.methods
+    .initializeReserve()
+    .accounts({
+      payer: authority.publicKey,
+      liquidityMint,
+      reserve,
+      liquiditySupply,
+      collateralMint,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .rpc();
+}
+
+export function findPoolAddresses(mintA: PublicKey, mintB: PublicKey): {
+  pool: PublicKey;
+  vaultA: PublicKey;
+  vaultB: PublicKey;
+} {
+  const programId = mockAmmProgram.programId;
+  const [pool] = PublicKey.findProgramAddressSync(
+    [Buffer.from('pool'), mintA.toBuffer(), mintB.toBuffer()],
+    programId
+  );
+  const [vaultA] = PublicKey.findProgramAddressSync(
+    [Buffer.from('pool_vault'), pool.toBuffer(), mintA.toBuffer()],
+    programId
+  );
+  const [vaultB] = PublicKey.findProgramAddressSync(
+    [Buffer.from('pool_vault'), pool.toBuffer(), mintB.toBuffer()],
+    programId
+  );
+  return { pool, vaultA, vaultB };
+}
+
+// A pool of `mintA` and `mintB` holding `amountA` and `amountB` from the authority's wallet.
+export async function initializePool(
+  mintA: PublicKey,
+  mintB: PublicKey,
+  amountA: number,
+  amountB: number
+): Promise<void> {
+  const connection = getConnection();
+  const { pool, vaultA, vaultB } = findPoolAddresses(mintA, mintB);
+  await mockAmmProgram.methods
+    .initializePool()
+    .accounts({
+      payer: authority.publicKey,
+      mintA,
+      mintB,
+      pool,
+      vaultA,
+      vaultB,
+      systemProgram: SystemProgram.programId,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .rpc();
+
+  const providerA = await getOrCreateAssociatedTokenAccount(connection, authority, mintA, authority.publicKey);
+  const providerB = await getOrCreateAssociatedTokenAccount(connection, authority, mintB, authority.publicKey);
+  await mockAmmProgram.methods
+    .addLiquidity(new anchor.BN(amountA), new anchor.BN(amountB))
+    .accounts({
+      provider: authority.publicKey,
+      pool,
+      vaultA,
+      vaultB,
+      providerA: providerA.address,
+      providerB: providerB.address,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .rpc();
+}
+
+export function findMarketAddresses(): { market: PublicKey; marginVault: PublicKey } {
+  const programId = mockPerpProgram.programId;
+  const [market] = PublicKey.findProgramAddressSync([Buffer.from('market')], programId);
+  const [marginVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from('margin_vault'), market.toBuffer()],
+    programId
+  );
+  return { market, marginVault };
+}
+
+export function findPositionAddress(owner: PublicKey): PublicKey {
+  const [position] = PublicKey.findProgramAddressSync(
+    [Buffer.from('position'), findMarketAddresses().market.toBuffer(), owner.toBuffer()],
+    mockPerpProgram.programId
+  );
+  return position;
+}
+
+// The mock perp market settles in `collateralMint`, its mark price is set to `markPrice`.
+export async function initializeMarketIfMissing(collateralMint: PublicKey, markPrice: number): Promise<void> {
+  const { market, marginVault } = findMarketAddresses();
+  if ((await getConnection().getAccountInfo(market)) === null) {
+    await mockPerpProgram.methods
+      .initializeMarket(new anchor.BN(markPrice))
+      .accounts({
+        authority: authority.publicKey,
+        market,
+        collateralMint,
+        marginVault,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  } else {
+    await setMarkPrice(markPrice);
+  }
+}
+
+export async function setMarkPrice(markPrice: number): Promise<void> {
+  await mockPerpProgram.methods
+    .setMarkPrice(new anchor.BN(markPrice))
+    .accounts({ authority: authority.publicKey, market: findMarketAddresses().market })
+    .rpc();
+}
+
+export async function initializePositionIfMissing(owner: PublicKey): Promise<void> {
+  const position = findPositionAddress(owner);
+  if ((await getConnection().getAccountInfo(position)) !== null) {
+    return;
+  }
+  await mockPerpProgram.methods
+    .initializePosition()
+    .accounts({
+      payer: authority.publicKey,
+      market: findMarketAddresses().market,
+      owner,
+      position,
+      systemProgram: SystemProgram.programId,
+    })
+    .rpc();
+}
+
+// Sets up everything `manage_hedging_strategy` needs for a strategy of `collateralMint`: the
+// strategy, its audit log and the mint's insurance fund.
+export async function initializeStrategyIfMissing(
+  strategyId: number,
+  collateralMint: PublicKey,
+  strategyType: object,
+  venueProgram: PublicKey,
+  venueMarket: PublicKey,
+  maxAllocation: number
+): Promise<void> {
+  const connection = getConnection();
+  if ((await xxusdClient.getHedgingStrategy(strategyId)) === null) {
+    await xxusdClient.initializeHedgingStrategy(
+      authority.publicKey,
+      collateralMint,
+      strategyId,
+      strategyType,
+      venueProgram,
+      venueMarket,
+      maxAllocation
+    );
+  }
+  if ((await connection.getAccountInfo(xxusdClient.findHedgingAuditLogAddress(strategyId))) === null) {
+    await xxusdClient.initializeHedgingAuditLog(authority.publicKey, strategyId);
+  }
+  if ((await connection.getAccountInfo(xxusdClient.findInsuranceFundAddress(collateralMint))) === null) {
+    await xxusdClient.initializeInsuranceFund(authority.publicKey, collateralMint, 1_000);
+  }
+}
+
+// A Lending strategy lending `collateralMint` to the mock reserve, with the token account
+// that holds its reserve collateral.
+export async function initializeLendingStrategyIfMissing(
+  strategyId: number,
+  collateralMint: PublicKey,
+  maxAllocation: number
+): Promise<void> {
+  await initializeReserveIfMissing(collateralMint);
+  const reserve = findReserveAddresses(collateralMint);
+  await initializeStrategyIfMissing(
+    strategyId,
+    collateralMint,
+    { lending: {} },
+    mockLendingProgram.programId,
+    reserve.reserve,
+    maxAllocation
+  );
+  await getOrCreateAssociatedTokenAccount(
+    getConnection(),
+    authority,
+    reserve.collateralMint,
+    xxusdClient.findHedgingStrategyAddress(strategyId),
+    true
+  );
+}
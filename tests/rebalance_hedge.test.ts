@@ -0,0 +1,91 @@
+import { Keypair } from '@solana/web3.js';
+import { expect } from 'chai';
+import { getAssociatedTokenAddressSync, getOrCreateAssociatedTokenAccount, transfer } from '@solana/spl-token';
+import { authority, xxusdClient, JUPSOL_DEVNET, JUPSOL_DEVNET_DECIMALS } from './constants';
+import { getConnection } from './connection';
+import {
+  findMarketAddresses,
+  findPositionAddress,
+  initializeMarketIfMissing,
+  initializePositionIfMissing,
+  initializeStrategyIfMissing,
+  mockPerpProgram,
+  setMarkPrice,
+} from './mocks';
+import { uiToNative } from './utils';
+
+describe('Rebalance Hedge', () => {
+  const connection = getConnection();
+  const strategyId = 3;
+  const markPrice = 100_000_000; // $100
+  const hedgingStrategyPda = xxusdClient.findHedgingStrategyAddress(strategyId);
+  const { market, marginVault } = findMarketAddresses();
+  const perpPosition = findPositionAddress(hedgingStrategyPda);
+  const insuranceFundVault = getAssociatedTokenAddressSync(
+    JUPSOL_DEVNET,
+    xxusdClient.findInsuranceFundAddress(JUPSOL_DEVNET),
+    true
+  );
+
+  before(async () => {
+    await initializeMarketIfMissing(JUPSOL_DEVNET, markPrice);
+    await initializeStrategyIfMissing(
+      strategyId,
+      JUPSOL_DEVNET,
+      { perpShort: {} },
+      mockPerpProgram.programId,
+      market,
+      uiToNative(100, JUPSOL_DEVNET_DECIMALS).toNumber()
+    );
+    await xxusdClient.editHedgingStrategy(authority.publicKey, strategyId, { isEnabled: true });
+    await initializePositionIfMissing(hedgingStrategyPda);
+
+    // The venue pays gains out of its margin vault
+    const authorityCollateral = await getOrCreateAssociatedTokenAccount(connection, authority, JUPSOL_DEVNET, authority.publicKey);
+    await transfer(connection, authority, authorityCollateral.address, marginVault, authority, uiToNative(1, JUPSOL_DEVNET_DECIMALS).toNumber());
+  });
+
+  it('should open a short hedging the collateral', async () => {
+    await xxusdClient.rebalanceHedge(authority.publicKey, strategyId, perpPosition, marginVault);
+
+    const position: any = await mockPerpProgram.account['position'].fetch(perpPosition);
+    const hedgingStrategy = await xxusdClient.getHedgingStrategy(strategyId);
+    expect(position.size.toNumber()).to.be.lessThan(0);
+    expect(hedgingStrategy.positionSize.toNumber()).to.equal(-position.size.toNumber());
+    expect(hedgingStrategy.markPrice.toNumber()).to.equal(markPrice);
+  });
+
+  it('should not let another wallet rebalance', async () => {
+    const stranger = Keypair.generate();
+
+    let failed = false;
+    try {
+      await xxusdClient.rebalanceHedge(stranger.publicKey, strategyId, perpPosition, marginVault);
+    } catch (e) {
+      failed = true;
+    }
+    expect(failed).to.equal(true);
+  });
+
+  it('should settle the gain of a buyback and pay the insurance fund its share', async () => {
+    const before = await xxusdClient.getHedgingStrategy(strategyId);
+    const insuranceBefore = await connection.getTokenAccountBalance(insuranceFundVault);
+
+    // The price fell 1%, a disabled strategy buys its whole short back
+    await setMarkPrice(markPrice * 0.99);
+    await xxusdClient.editHedgingStrategy(authority.publicKey, strategyId, { isEnabled: false });
+    await xxusdClient.rebalanceHedge(authority.publicKey, strategyId, perpPosition, marginVault);
+
+    const position: any = await mockPerpProgram.account['position'].fetch(perpPosition);
+    const hedgingStrategy = await xxusdClient.getHedgingStrategy(strategyId);
+    const insurance = await connection.getTokenAccountBalance(insuranceFundVault);
+    expect(position.size.toNumber()).to.equal(0);
+    expect(hedgingStrategy.realizedPnl.sub(before.realizedPnl).toNumber()).to.be.greaterThan(0);
+    expect(Number(insurance.value.amount)).to.be.greaterThan(Number(insuranceBefore.value.amount));
+  });
+
+  after(async () => {
+    await setMarkPrice(markPrice);
+    await xxusdClient.editHedgingStrategy(authority.publicKey, strategyId, { isEnabled: true });
+  });
+});
@@ -0,0 +1,122 @@
+import * as anchor from '@project-serum/anchor';
+import { PublicKey, TransactionInstruction } from '@solana/web3.js';
+import { expect } from 'chai';
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from '@solana/spl-token';
+import { authority, xxusdClient, JUPSOL_DEVNET, JUPSOL_DEVNET_DECIMALS } from './constants';
+import { getConnection } from './connection';
+import {
+  findPoolAddresses,
+  findReserveAddresses,
+  initializeLendingStrategyIfMissing,
+  initializePool,
+  mockAmmProgram,
+} from './mocks';
+import { uiToNative } from './utils';
+
+describe('Swap Assets', () => {
+  const connection = getConnection();
+  const strategyId = 2;
+  const hedgingStrategyPda = xxusdClient.findHedgingStrategyAddress(strategyId);
+  const hedgingStrategyVault = getAssociatedTokenAddressSync(JUPSOL_DEVNET, hedgingStrategyPda, true);
+  const amountIn = uiToNative(1, 9).toNumber();
+  // A token the strategy holds besides its collateral, swapped back into jupSOL
+  let otherMint: PublicKey;
+  let strategyOtherAccount: PublicKey;
+
+  // The mock AMM's swap, with the strategy PDA as the swap's authority
+  async function buildSwapInstruction(sourceMint: PublicKey, destinationMint: PublicKey): Promise<TransactionInstruction> {
+    const { pool, vaultA, vaultB } = findPoolAddresses(otherMint, JUPSOL_DEVNET);
+    const isSourceA = sourceMint.equals(otherMint);
+    return mockAmmProgram.methods
+      .swap(new anchor.BN(amountIn), new anchor.BN(0))
+      .accounts({
+        pool,
+        userAuthority: hedgingStrategyPda,
+        userSource: getAssociatedTokenAddressSync(sourceMint, hedgingStrategyPda, true),
+        userDestination: getAssociatedTokenAddressSync(destinationMint, hedgingStrategyPda, true),
+        vaultIn: isSourceA ? vaultA : vaultB,
+        vaultOut: isSourceA ? vaultB : vaultA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .instruction();
+  }
+
+  before(async () => {
+    await initializeLendingStrategyIfMissing(strategyId, JUPSOL_DEVNET, uiToNative(100, JUPSOL_DEVNET_DECIMALS).toNumber());
+    if ((await connection.getAccountInfo(xxusdClient.findSwapProgramAddress(mockAmmProgram.programId))) === null) {
+      await xxusdClient.registerSwapProgram(authority.publicKey, mockAmmProgram.programId);
+    }
+
+    otherMint = await createMint(connection, authority, authority.publicKey, null, 9);
+    const authorityOther = await getOrCreateAssociatedTokenAccount(connection, authority, otherMint, authority.publicKey);
+    await mintTo(connection, authority, otherMint, authorityOther.address, authority, uiToNative(100, 9).toNumber());
+    await initializePool(otherMint, JUPSOL_DEVNET, uiToNative(10, 9).toNumber(), uiToNative(0.1, JUPSOL_DEVNET_DECIMALS).toNumber());
+
+    strategyOtherAccount = (
+      await getOrCreateAssociatedTokenAccount(connection, authority, otherMint, hedgingStrategyPda, true)
+    ).address;
+    await mintTo(connection, authority, otherMint, strategyOtherAccount, authority, uiToNative(5, 9).toNumber());
+  });
+
+  it('should swap another token of the strategy into its collateral vault', async () => {
+    const vaultBefore = await connection.getTokenAccountBalance(hedgingStrategyVault);
+    const otherBefore = await connection.getTokenAccountBalance(strategyOtherAccount);
+
+    await xxusdClient.swapAssets(
+      authority.publicKey,
+      strategyId,
+      otherMint,
+      JUPSOL_DEVNET,
+      amountIn,
+      1,
+      await buildSwapInstruction(otherMint, JUPSOL_DEVNET)
+    );
+
+    const vault = await connection.getTokenAccountBalance(hedgingStrategyVault);
+    const other = await connection.getTokenAccountBalance(strategyOtherAccount);
+    expect(Number(otherBefore.value.amount) - Number(other.value.amount)).to.equal(amountIn);
+    expect(Number(vault.value.amount)).to.be.greaterThan(Number(vaultBefore.value.amount));
+  });
+
+  it('should not swap the collateral out of its vault', async () => {
+    let failed = false;
+    try {
+      await xxusdClient.swapAssets(
+        authority.publicKey,
+        strategyId,
+        JUPSOL_DEVNET,
+        otherMint,
+        amountIn,
+        0,
+        await buildSwapInstruction(JUPSOL_DEVNET, otherMint)
+      );
+    } catch (e) {
+      failed = true;
+    }
+    expect(failed).to.equal(true);
+  });
+
+  it('should not let the AMM write to other token accounts of the strategy', async () => {
+    const swapInstruction = await buildSwapInstruction(otherMint, JUPSOL_DEVNET);
+    // The strategy's reserve collateral, which the swap's balance checks do not cover
+    swapInstruction.keys.push({
+      pubkey: getAssociatedTokenAddressSync(findReserveAddresses(JUPSOL_DEVNET).collateralMint, hedgingStrategyPda, true),
+      isSigner: false,
+      isWritable: true,
+    });
+
+    let failed = false;
+    try {
+      await xxusdClient.swapAssets(authority.publicKey, strategyId, otherMint, JUPSOL_DEVNET, amountIn, 1, swapInstruction);
+    } catch (e) {
+      failed = true;
+    }
+    expect(failed).to.equal(true);
+  });
+});